@@ -0,0 +1,116 @@
+//! JSON config files under the host's configs directory
+//!
+//! `name` is a path relative to the directory returned by [`configs_path`](crate::configs_path),
+//! e.g. `"settings.json"` or `"maps/de_dust2.json"`. Absolute paths and `..`
+//! components are rejected, so a name can't reach outside that directory. Both
+//! functions panic if called before `plugify_init`, like the path accessors
+//! they build on.
+
+use std::path::{Component, Path, PathBuf};
+use serde::{de::DeserializeOwned, Serialize};
+use crate::config_file;
+
+/// Error returned when a config file cannot be read, written or (de)serialized
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The name is absolute or contains `..`
+    InvalidName(PathBuf),
+    /// The file could not be read or written
+    Io(PathBuf, std::io::Error),
+    /// The contents are not valid JSON for the requested type
+    Json(PathBuf, serde_json::Error),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::InvalidName(name) => write!(f, "config {}: not a relative path inside the configs directory", name.display()),
+            ConfigError::Io(path, err) => write!(f, "config {}: {}", path.display(), err),
+            ConfigError::Json(path, err) => write!(f, "config {}: invalid JSON: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::InvalidName(_) => None,
+            ConfigError::Io(_, err) => Some(err),
+            ConfigError::Json(_, err) => Some(err),
+        }
+    }
+}
+
+fn resolve(name: &str) -> Result<PathBuf, ConfigError> {
+    let relative = Path::new(name);
+    if relative.components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir)) {
+        Ok(config_file(name))
+    } else {
+        Err(ConfigError::InvalidName(relative.to_path_buf()))
+    }
+}
+
+/// Read the config file `name` and deserialize it as JSON
+pub fn load_json<T: DeserializeOwned>(name: &str) -> Result<T, ConfigError> {
+    let path = resolve(name)?;
+    let text = std::fs::read_to_string(&path).map_err(|err| ConfigError::Io(path.clone(), err))?;
+    serde_json::from_str(&text).map_err(|err| ConfigError::Json(path, err))
+}
+
+/// Serialize `value` as pretty-printed JSON into the config file `name`
+///
+/// Missing parent directories are created. An existing file is replaced.
+pub fn save_json<T: Serialize + ?Sized>(name: &str, value: &T) -> Result<(), ConfigError> {
+    let path = resolve(name)?;
+    let text = serde_json::to_string_pretty(value).map_err(|err| ConfigError::Json(path.clone(), err))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| ConfigError::Io(path.clone(), err))?;
+    }
+    std::fs::write(&path, text).map_err(|err| ConfigError::Io(path, err))
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Settings {
+        name: String,
+        volume: f32,
+        maps: Vec<String>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let _guard = mock::lock_globals();
+        let dir = std::env::temp_dir().join(format!("plugify-config-{}", std::process::id()));
+        mock::set_configs_dir(dir.to_str().unwrap());
+
+        let settings = Settings {
+            name: "server".to_string(),
+            volume: 0.5,
+            maps: vec!["de_dust2".to_string(), "cs_office".to_string()],
+        };
+        save_json("nested/settings.json", &settings).unwrap();
+        assert!(dir.join("nested/settings.json").is_file());
+        assert_eq!(load_json::<Settings>("nested/settings.json").unwrap(), settings);
+
+        assert!(matches!(load_json::<Settings>("missing.json"), Err(ConfigError::Io(..))));
+        std::fs::write(dir.join("broken.json"), "{ not json").unwrap();
+        assert!(matches!(load_json::<Settings>("broken.json"), Err(ConfigError::Json(..))));
+
+        for name in ["/etc/settings.json", "../settings.json", "nested/../../settings.json"] {
+            assert!(matches!(save_json(name, &settings), Err(ConfigError::InvalidName(_))), "{}", name);
+            assert!(matches!(load_json::<Settings>(name), Err(ConfigError::InvalidName(_))), "{}", name);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        crate::plugify_deinit();
+    }
+}
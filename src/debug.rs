@@ -0,0 +1,42 @@
+//! Diagnostics for hosts that don't provide every symbol
+//!
+//! Every `import_symbol!` wrapper registers itself here the first time its
+//! init function runs, so [`symbol_status`] can report which ones are still null
+//! and `plugify_deinit` can clear them all.
+
+use std::sync::Mutex;
+
+/// Symbol name, a check for whether its wrapper is initialized, and a way to clear it
+type Entry = (&'static str, fn() -> bool, fn());
+
+static REGISTRY: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+/// Record an imported symbol, how to check whether it is initialized and how to clear it
+///
+/// Public only so the exported `import_symbol!` macro can call it.
+#[doc(hidden)]
+pub fn register(name: &'static str, status: fn() -> bool, reset: fn()) {
+    let mut registry = REGISTRY.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    if !registry.iter().any(|&(registered, _, _)| registered == name) {
+        registry.push((name, status, reset));
+    }
+}
+
+/// Clear every registered wrapper, for `plugify_deinit`
+pub(crate) fn reset_all() {
+    let registry = REGISTRY.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    for &(_, _, reset) in registry.iter() {
+        reset();
+    }
+}
+
+/// List every registered imported symbol and whether its wrapper is initialized
+///
+/// Symbols appear in the order they were first registered, which for
+/// `plugify_init` is the order of the host's function table. Optional symbols
+/// the host did not provide are listed with `false`.
+#[must_use]
+pub fn symbol_status() -> Vec<(&'static str, bool)> {
+    let registry = REGISTRY.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    registry.iter().map(|&(name, status, _)| (name, status())).collect()
+}
@@ -0,0 +1,119 @@
+use crate::{import_symbol, Str, Arr, Var};
+use crate::variant::VAR_TYPE_OFFSET;
+
+import_symbol!(get_layout_descriptor, GET_LAYOUT_DESCRIPTOR, init_get_layout_descriptor, has_layout_descriptor, () -> LayoutDescriptor);
+
+// Sizes are asserted next to each type; alignment is fixed by the host ABI too
+const _: () = assert!(align_of::<Str>() == align_of::<*const ()>());
+const _: () = assert!(align_of::<Arr<u8>>() == align_of::<*const ()>());
+
+/// Sizes, alignments and offsets of the FFI types
+///
+/// Newer hosts export a symbol returning their view of these, which
+/// `verify_layout` compares against `LayoutDescriptor::native()`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutDescriptor {
+    pub string_size: usize,
+    pub string_align: usize,
+    pub vector_size: usize,
+    pub vector_align: usize,
+    pub variant_size: usize,
+    pub variant_align: usize,
+    /// Byte offset of the type tag within a variant
+    pub variant_type_offset: usize,
+}
+
+impl LayoutDescriptor {
+    /// Layout of this crate's FFI types on the current target
+    #[must_use]
+    pub const fn native() -> Self {
+        Self {
+            string_size: size_of::<Str>(),
+            string_align: align_of::<Str>(),
+            vector_size: size_of::<Arr<u8>>(),
+            vector_align: align_of::<Arr<u8>>(),
+            variant_size: size_of::<Var>(),
+            variant_align: align_of::<Var>(),
+            variant_type_offset: VAR_TYPE_OFFSET,
+        }
+    }
+
+    /// Compare against a host-reported layout, returning the first field that differs
+    ///
+    /// # Errors
+    ///
+    /// Returns a `LayoutError` naming the mismatched field.
+    pub fn check(&self, host: &LayoutDescriptor) -> Result<(), LayoutError> {
+        let fields = [
+            ("string_size", self.string_size, host.string_size),
+            ("string_align", self.string_align, host.string_align),
+            ("vector_size", self.vector_size, host.vector_size),
+            ("vector_align", self.vector_align, host.vector_align),
+            ("variant_size", self.variant_size, host.variant_size),
+            ("variant_align", self.variant_align, host.variant_align),
+            ("variant_type_offset", self.variant_type_offset, host.variant_type_offset),
+        ];
+        match fields.into_iter().find(|(_, plugin, host)| plugin != host) {
+            Some((field, plugin, host)) => Err(LayoutError { field, plugin, host }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A layout field on which the plugin and host disagree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutError {
+    pub field: &'static str,
+    pub plugin: usize,
+    pub host: usize,
+}
+
+impl std::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FFI layout mismatch in {}: plugin has {}, host has {}", self.field, self.plugin, self.host)
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// Check that the host agrees with this crate on the FFI type layouts
+///
+/// Sizes are already checked at compile time against the documented ABI; this
+/// additionally cross-checks against the host's layout descriptor when the host
+/// provides one, and succeeds otherwise. `plugify_init` calls it automatically.
+///
+/// # Errors
+///
+/// Returns a `LayoutError` for the first field on which the layouts differ.
+pub fn verify_layout() -> Result<(), LayoutError> {
+    if has_layout_descriptor() {
+        LayoutDescriptor::native().check(&get_layout_descriptor())?;
+    }
+    Ok(())
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_native_layout() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+
+        let native = LayoutDescriptor::native();
+        assert_eq!(native.check(&native), Ok(()));
+        assert_eq!(native.variant_size, 32);
+        assert_eq!(native.variant_type_offset, 24);
+        assert_eq!(verify_layout(), Ok(()));
+
+        let host = LayoutDescriptor { variant_type_offset: 16, ..native };
+        let err = native.check(&host).unwrap_err();
+        assert_eq!(err, LayoutError { field: "variant_type_offset", plugin: 24, host: 16 });
+    }
+}
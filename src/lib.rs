@@ -1,3 +1,14 @@
+//! Rust bindings for writing plugify plugins
+//!
+//! # Element access
+//!
+//! `Arr`, `Vec2`/`Vec3`/`Vec4` and `Mat4x4` share one policy: indexing with
+//! `[]` panics when out of bounds, `get`/`get_mut` return `None` instead, and
+//! `Arr` additionally has `try_get`/`try_get_mut` returning an
+//! [`IndexOutOfBounds`] error with the index and length. `Str` derefs to `str`,
+//! so it follows `str`'s rules: byte ranges, with `get` returning `None` for a
+//! range that is out of bounds or not on a char boundary.
+
 pub mod string;
 pub mod vector;
 pub mod variant;
@@ -6,6 +17,15 @@ pub mod vec2;
 pub mod vec3;
 pub mod vec4;
 pub mod plugin;
+pub mod layout;
+pub mod policy;
+pub mod debug;
+
+#[cfg(feature = "json-config")]
+pub mod config;
+
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
 
 pub use string::*;
 pub use vector::*;
@@ -15,6 +35,8 @@ pub use vec2::*;
 pub use vec3::*;
 pub use vec4::*;
 pub use plugin::*;
+pub use layout::*;
+pub use policy::*;
 
 /// Export plugify runtime symbols to prevent linker stripping.
 ///
@@ -45,6 +67,7 @@ macro_rules! export_symbols {
     () => {
         unsafe extern "C" {
             fn plugify_init() -> *const ();
+            fn plugify_deinit() -> *const ();
             fn plugify_plugin_start() -> *const ();
             fn plugify_plugin_update() -> *const ();
             fn plugify_plugin_end() -> *const ();
@@ -54,6 +77,9 @@ macro_rules! export_symbols {
         #[used]
         static PLUGIFY_INIT_REF: unsafe extern "C" fn() -> *const () = plugify_init;
 
+        #[used]
+        static PLUGIFY_DEINIT_REF: unsafe extern "C" fn() -> *const () = plugify_deinit;
+
         #[used]
         static PLUGIFY_PLUGIN_START_REF: unsafe extern "C" fn() -> *const () = plugify_plugin_start;
 
@@ -78,6 +104,10 @@ macro_rules! export_symbols {
 /// - `$name` - Safe wrapper function name
 /// - `$func_name` - Static variable name for storage
 /// - `$init_name` - Initialization function name
+/// - `$has_name` - Optional: name of a generated `fn() -> bool` reporting whether
+///   the host provided the symbol, for symbols older hosts may lack
+/// - A leading `unsafe` makes the wrapper an `unsafe fn`, for symbols whose
+///   pointer arguments the host dereferences
 /// - Signature: `($arg: $type, ...) -> $return`
 ///
 /// # Example
@@ -103,6 +133,26 @@ macro_rules! export_symbols {
 /// }
 /// ```
 ///
+/// Symbols that older hosts may not provide also take a `$has_name`:
+///
+/// ```
+/// plugify::import_symbol!(flush_log, FLUSH_LOG_PTR, init_flush_log, has_flush_log, () -> ());
+///
+/// fn flush() {
+///     if has_flush_log() {
+///         flush_log();
+///     }
+/// }
+/// # fn main() { flush(); }
+/// ```
+///
+/// # Initialization
+///
+/// `$init_name(addr)` may be called any number of times; each call replaces the
+/// stored pointer. An `addr` of 0 means the host lacks the symbol and clears the
+/// wrapper. Every init function also registers the symbol with [`debug`], which
+/// lets `plugify_deinit` clear all wrappers so no pointer outlives a reload.
+///
 /// # Safety
 ///
 /// Uses `transmute` to convert `usize` to function pointer. Host must provide:
@@ -111,19 +161,68 @@ macro_rules! export_symbols {
 ///
 /// # Panics
 ///
-/// - Init function called twice
-/// - Wrapper called before initialization
+/// The wrapper panics if called while the symbol is unset, i.e. before
+/// initialization, after `plugify_deinit`, or when the host passed 0.
 #[macro_export]
 macro_rules! import_symbol {
-    ($name:ident, $func_name:ident, $init_name:ident, ($($arg_name:ident : $arg_ty:ty),*) -> $ret:ty) => {
+    // Storage and init function shared by every form
+    (@init $name:ident, $func_name:ident, $init_name:ident, ($($arg_ty:ty),*) -> $ret:ty) => {
         static mut $func_name: Option<unsafe extern "C" fn($($arg_ty),*) -> $ret> = None;
 
         pub fn $init_name(addr: usize) {
+            fn status() -> bool {
+                unsafe {
+                    let func = $func_name;
+                    func.is_some()
+                }
+            }
+            fn reset() {
+                unsafe { $func_name = None; }
+            }
+            $crate::debug::register(stringify!($name), status, reset);
             unsafe {
-                let ptr = std::mem::transmute::<usize, unsafe extern "C" fn($($arg_ty),*) -> $ret>(addr);
-                $func_name = Some(ptr);
+                $func_name = if addr == 0 {
+                    None
+                } else {
+                    Some(std::mem::transmute::<usize, unsafe extern "C" fn($($arg_ty),*) -> $ret>(addr))
+                };
             }
         }
+    };
+    (@has $func_name:ident, $has_name:ident) => {
+        pub fn $has_name() -> bool {
+            unsafe {
+                let func = $func_name;
+                func.is_some()
+            }
+        }
+    };
+    // Optional symbols also get a `$has_name()` check, for hosts that may not provide them
+    (unsafe $name:ident, $func_name:ident, $init_name:ident, $has_name:ident, ($($arg_name:ident : $arg_ty:ty),*) -> $ret:ty) => {
+        $crate::import_symbol!(unsafe $name, $func_name, $init_name, ($($arg_name : $arg_ty),*) -> $ret);
+        $crate::import_symbol!(@has $func_name, $has_name);
+    };
+    ($name:ident, $func_name:ident, $init_name:ident, $has_name:ident, ($($arg_name:ident : $arg_ty:ty),*) -> $ret:ty) => {
+        $crate::import_symbol!($name, $func_name, $init_name, ($($arg_name : $arg_ty),*) -> $ret);
+        $crate::import_symbol!(@has $func_name, $has_name);
+    };
+    // Wrappers taking pointers the host dereferences are `unsafe fn`
+    (unsafe $name:ident, $func_name:ident, $init_name:ident, ($($arg_name:ident : $arg_ty:ty),*) -> $ret:ty) => {
+        $crate::import_symbol!(@init $name, $func_name, $init_name, ($($arg_ty),*) -> $ret);
+
+        /// # Safety
+        ///
+        /// Pointer arguments must be valid for the reads and writes the host
+        /// function performs through them.
+        pub unsafe fn $name($($arg_name: $arg_ty),*) -> $ret {
+            unsafe {
+                let func = $func_name.expect("Function not initialized");
+                func($($arg_name),*)
+            }
+        }
+    };
+    ($name:ident, $func_name:ident, $init_name:ident, ($($arg_name:ident : $arg_ty:ty),*) -> $ret:ty) => {
+        $crate::import_symbol!(@init $name, $func_name, $init_name, ($($arg_ty),*) -> $ret);
 
         pub fn $name($($arg_name: $arg_ty),*) -> $ret {
             unsafe {
@@ -209,3 +308,48 @@ macro_rules! register_plugin {
         $crate::export_symbols!();
     };
 }
+
+/// Create a `Str` using `format!` syntax.
+///
+/// A convenience wrapper that saves no allocations: the arguments are
+/// formatted into a Rust `String`, which is then copied into a new host string.
+/// The host API can only fill a string from caller memory, so there is no way
+/// to format straight into its buffer.
+///
+/// # Example
+///
+/// ```no_run
+/// use plugify::*;
+///
+/// let s: Str = plg_format!("x={}", 42);
+/// assert_eq!(s, "x=42");
+/// ```
+#[macro_export]
+macro_rules! plg_format {
+    ($($arg:tt)*) => {
+        $crate::Str::from_str(&::std::fmt::format(::std::format_args!($($arg)*)))
+    };
+}
+
+/// Build an `Arr<Var>` from a list of values convertible into `Any`.
+///
+/// Each value goes through `Into<Any>`, so mixed types can be passed directly
+/// as an argument list for host calls.
+///
+/// # Example
+///
+/// ```no_run
+/// use plugify::*;
+///
+/// let args: Arr<Var> = arr_var![1i32, "hello", 3.14f64];
+/// assert_eq!(args.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! arr_var {
+    () => {
+        $crate::Arr::<$crate::Var>::new()
+    };
+    ($($value:expr),+ $(,)?) => {
+        $crate::Arr::<$crate::Var>::from(&[$($crate::Any::from($value)),+][..])
+    };
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 use std::ops::{Add, Sub, Mul, Neg, Index, IndexMut, AddAssign, SubAssign, MulAssign};
-use crate::Vec4;
+use crate::{Vec3, Vec4};
 
 /// A 4x4 matrix with f32 components
 ///
@@ -23,6 +23,9 @@ pub struct Mat4x4 {
 const EPSILON: f32 = 1e-6;
 
 impl Mat4x4 {
+    /// Storage order of `m`; always true, for code that branches on layout at compile time
+    pub const ROW_MAJOR: bool = true;
+
     /// Create a new matrix from a 2D array
     ///
     /// Elements are provided in row-major order: `[[row0], [row1], [row2], [row3]]`
@@ -167,8 +170,122 @@ impl Mat4x4 {
         }
     }
 
+    /// Create a shear matrix moving x in proportion to y
+    ///
+    /// Maps `(x, y)` to `(x + factor * y, y)`; other components are unchanged.
+    ///
+    /// All shear constructors are named `shear_<moved axis><source axis>`.
+    #[must_use]
+    pub fn shear_xy(factor: f32) -> Self {
+        Self {
+            m: [
+                [1.0, factor, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Create a shear matrix moving x in proportion to z
+    ///
+    /// Maps `(x, z)` to `(x + factor * z, z)`; other components are unchanged.
+    #[must_use]
+    pub fn shear_xz(factor: f32) -> Self {
+        Self {
+            m: [
+                [1.0, 0.0, factor, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Create a shear matrix moving y in proportion to x
+    ///
+    /// Maps `(y, x)` to `(y + factor * x, x)`; other components are unchanged.
+    #[must_use]
+    pub fn shear_yx(factor: f32) -> Self {
+        Self {
+            m: [
+                [1.0, 0.0, 0.0, 0.0],
+                [factor, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Create a shear matrix moving y in proportion to z
+    ///
+    /// Maps `(y, z)` to `(y + factor * z, z)`; other components are unchanged.
+    #[must_use]
+    pub fn shear_yz(factor: f32) -> Self {
+        Self {
+            m: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, factor, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Create a shear matrix moving z in proportion to x
+    ///
+    /// Maps `(z, x)` to `(z + factor * x, x)`; other components are unchanged.
+    #[must_use]
+    pub fn shear_zx(factor: f32) -> Self {
+        Self {
+            m: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [factor, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Create a shear matrix moving z in proportion to y
+    ///
+    /// Maps `(z, y)` to `(z + factor * y, y)`; other components are unchanged.
+    #[must_use]
+    pub fn shear_zy(factor: f32) -> Self {
+        Self {
+            m: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, factor, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Create a transform from Euler angles, a translation and a scale
+    ///
+    /// Angles are in radians, stored as `(x, y, z)` rotations about each axis.
+    /// Rotation order is ZYX: the point is rotated about X first, then Y, then Z,
+    /// so the result equals `translation(t) * rotation_z(z) * rotation_y(y) * rotation_x(x) * scaling(s)`.
+    #[must_use]
+    pub fn from_euler_translation_scale(euler: Vec3, translation: Vec3, scale: Vec3) -> Self {
+        let rotation = Self::rotation_z(euler.z) * Self::rotation_y(euler.y) * Self::rotation_x(euler.x);
+        let mut m = rotation;
+        for row in m.m.iter_mut().take(3) {
+            row[0] *= scale.x;
+            row[1] *= scale.y;
+            row[2] *= scale.z;
+        }
+        m.m[0][3] = translation.x;
+        m.m[1][3] = translation.y;
+        m.m[2][3] = translation.z;
+        m
+    }
+
     /// Create a perspective projection matrix
     ///
+    /// Right-handed with OpenGL's [-1, 1] depth range.
+    ///
     /// # Arguments
     /// * `fov` - Field of view in radians
     /// * `aspect` - Aspect ratio (width / height)
@@ -189,8 +306,61 @@ impl Mat4x4 {
         }
     }
 
+    /// Create a perspective projection matrix with a [0, 1] depth range
+    ///
+    /// Right-handed, maps the near plane to depth 0 and the far plane to depth 1.
+    /// Suits Vulkan, Direct3D and Metal. Use `perspective` for OpenGL's [-1, 1] range.
+    ///
+    /// # Arguments
+    /// * `fov` - Field of view in radians
+    /// * `aspect` - Aspect ratio (width / height)
+    /// * `near` - Near clipping plane
+    /// * `far` - Far clipping plane
+    #[must_use]
+    pub fn perspective_zo(fov: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / (fov / 2.0).tan();
+        let nf = 1.0 / (near - far);
+
+        Self {
+            m: [
+                [f / aspect, 0.0, 0.0, 0.0],
+                [0.0, f, 0.0, 0.0],
+                [0.0, 0.0, far * nf, far * near * nf],
+                [0.0, 0.0, -1.0, 0.0],
+            ],
+        }
+    }
+
+    /// Create a reversed-Z perspective projection matrix with a [0, 1] depth range
+    ///
+    /// Right-handed, maps the near plane to depth 1 and the far plane to depth 0.
+    /// Pair with a floating point depth buffer and a `GREATER` depth test on
+    /// Vulkan/Direct3D for much better precision at distance.
+    ///
+    /// # Arguments
+    /// * `fov` - Field of view in radians
+    /// * `aspect` - Aspect ratio (width / height)
+    /// * `near` - Near clipping plane
+    /// * `far` - Far clipping plane
+    #[must_use]
+    pub fn perspective_reversed_z(fov: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / (fov / 2.0).tan();
+        let fn_ = 1.0 / (far - near);
+
+        Self {
+            m: [
+                [f / aspect, 0.0, 0.0, 0.0],
+                [0.0, f, 0.0, 0.0],
+                [0.0, 0.0, near * fn_, far * near * fn_],
+                [0.0, 0.0, -1.0, 0.0],
+            ],
+        }
+    }
+
     /// Create an orthographic projection matrix
     ///
+    /// Right-handed with OpenGL's [-1, 1] depth range.
+    ///
     /// # Arguments
     /// * `left`, `right` - Left and right clipping planes
     /// * `bottom`, `top` - Bottom and top clipping planes
@@ -211,6 +381,56 @@ impl Mat4x4 {
         }
     }
 
+    /// Create an orthographic projection matrix with a [0, 1] depth range
+    ///
+    /// Right-handed, maps the near plane to depth 0 and the far plane to depth 1.
+    /// Suits Vulkan, Direct3D and Metal. Use `orthographic` for OpenGL's [-1, 1] range.
+    ///
+    /// # Arguments
+    /// * `left`, `right` - Left and right clipping planes
+    /// * `bottom`, `top` - Bottom and top clipping planes
+    /// * `near`, `far` - Near and far clipping planes
+    #[must_use]
+    pub fn orthographic_zo(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let rl = 1.0 / (right - left);
+        let tb = 1.0 / (top - bottom);
+        let fn_ = 1.0 / (far - near);
+
+        Self {
+            m: [
+                [2.0 * rl, 0.0, 0.0, -(right + left) * rl],
+                [0.0, 2.0 * tb, 0.0, -(top + bottom) * tb],
+                [0.0, 0.0, -fn_, -near * fn_],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Create a reversed-Z orthographic projection matrix with a [0, 1] depth range
+    ///
+    /// Right-handed, maps the near plane to depth 1 and the far plane to depth 0.
+    /// Intended for Vulkan/Direct3D renderers that use reversed-Z throughout.
+    ///
+    /// # Arguments
+    /// * `left`, `right` - Left and right clipping planes
+    /// * `bottom`, `top` - Bottom and top clipping planes
+    /// * `near`, `far` - Near and far clipping planes
+    #[must_use]
+    pub fn orthographic_reversed_z(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let rl = 1.0 / (right - left);
+        let tb = 1.0 / (top - bottom);
+        let fn_ = 1.0 / (far - near);
+
+        Self {
+            m: [
+                [2.0 * rl, 0.0, 0.0, -(right + left) * rl],
+                [0.0, 2.0 * tb, 0.0, -(top + bottom) * tb],
+                [0.0, 0.0, fn_, far * fn_],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
     /// Create a look-at view matrix
     ///
     /// # Arguments
@@ -261,9 +481,56 @@ impl Mat4x4 {
         }
     }
 
+    /// Create a transform at `position` whose `forward()` faces the camera
+    ///
+    /// Used for sprites and particles: the quad's -Z axis points at
+    /// `camera_pos` and its up axis stays as close to `up` as possible. If the
+    /// camera is straight along `up`, another up direction is picked.
+    #[must_use]
+    pub fn billboard(position: Vec3, camera_pos: Vec3, up: Vec3) -> Self {
+        let forward = (camera_pos - position).try_normalize().unwrap_or(Vec3::back());
+        let back = -forward;
+        let right = up.cross(back).try_normalize()
+            .or_else(|| Vec3::forward().cross(back).try_normalize())
+            .unwrap_or(Vec3::right());
+        Self::from_basis(right, back.cross(right), back, position)
+    }
+
+    /// Create a billboard that only rotates around `axis`
+    ///
+    /// Used for trees and grass: the up axis stays fixed to `axis` and the
+    /// forward direction is the direction to the camera flattened onto the
+    /// plane perpendicular to it.
+    #[must_use]
+    pub fn billboard_axis(position: Vec3, camera_pos: Vec3, axis: Vec3) -> Self {
+        let up = axis.try_normalize().unwrap_or(Vec3::up());
+        let to_camera = camera_pos - position;
+        let flat = to_camera - up * to_camera.dot(up);
+        let forward = flat.try_normalize()
+            .or_else(|| up.cross(Vec3::right()).try_normalize())
+            .unwrap_or_else(|| up.cross(Vec3::forward()).normalize());
+        let back = -forward;
+        Self::from_basis(up.cross(back), up, back, position)
+    }
+
+    /// Build a transform from right/up/back basis columns and a translation
+    fn from_basis(right: Vec3, up: Vec3, back: Vec3, translation: Vec3) -> Self {
+        Self {
+            m: [
+                [right.x, up.x, back.x, translation.x],
+                [right.y, up.y, back.y, translation.y],
+                [right.z, up.z, back.z, translation.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
     /// Add another matrix to this one
     ///
     /// **Deprecated:** Use the `+` operator instead: `m1 + m2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the + operator instead")]
     #[must_use]
     pub fn add(&self, matrix: Mat4x4) -> Mat4x4 {
@@ -273,6 +540,9 @@ impl Mat4x4 {
     /// Subtract another matrix from this one
     ///
     /// **Deprecated:** Use the `-` operator instead: `m1 - m2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the - operator instead")]
     #[must_use]
     pub fn subtract(&self, matrix: Mat4x4) -> Mat4x4 {
@@ -282,6 +552,9 @@ impl Mat4x4 {
     /// Multiply this matrix by another matrix
     ///
     /// **Deprecated:** Use the `*` operator instead: `m1 * m2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the * operator instead")]
     #[must_use]
     pub fn multiply(&self, matrix: Mat4x4) -> Mat4x4 {
@@ -291,6 +564,9 @@ impl Mat4x4 {
     /// Multiply this matrix by a vector
     ///
     /// **Deprecated:** Use the `*` operator instead: `matrix * vector`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the * operator instead")]
     #[must_use]
     pub fn multiply_vector(&self, vector: Vec4) -> Vec4 {
@@ -416,14 +692,128 @@ impl Mat4x4 {
         Some(Mat4x4 { m: result })
     }
 
+    /// LU-decompose the matrix in f64 with partial pivoting
+    ///
+    /// Returns the combined L\U factors (unit lower diagonal implied), the row
+    /// permutation and the permutation sign. Columns with no usable pivot are
+    /// left in place, leaving a zero on the diagonal.
+    fn lu(&self) -> ([[f64; 4]; 4], [usize; 4], f64) {
+        let mut lu = self.m.map(|row| row.map(f64::from));
+        let mut perm = [0, 1, 2, 3];
+        let mut sign = 1.0;
+
+        for k in 0..4 {
+            let pivot = (k..4)
+                .max_by(|&a, &b| lu[a][k].abs().total_cmp(&lu[b][k].abs()))
+                .unwrap_or(k);
+            if lu[pivot][k] == 0.0 {
+                continue;
+            }
+            if pivot != k {
+                lu.swap(pivot, k);
+                perm.swap(pivot, k);
+                sign = -sign;
+            }
+            let pivot_row = lu[k];
+            for row in lu.iter_mut().skip(k + 1) {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (value, p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *value -= factor * p;
+                }
+            }
+        }
+
+        (lu, perm, sign)
+    }
+
+    /// Calculate the determinant using LU decomposition
+    ///
+    /// Works in f64 with partial pivoting, so it loses less precision than
+    /// `determinant` on badly scaled matrices, at the cost of a few more operations.
+    #[must_use]
+    pub fn determinant_lu(&self) -> f32 {
+        let (lu, _, sign) = self.lu();
+        (sign * lu[0][0] * lu[1][1] * lu[2][2] * lu[3][3]) as f32
+    }
+
+    /// Calculate the inverse using LU decomposition
+    ///
+    /// Unlike `inverse`, which rejects any matrix whose determinant is below a
+    /// fixed epsilon, this compares each pivot against the scale of the matrix.
+    /// Uniformly small but well-conditioned matrices still invert, while
+    /// matrices that are singular at f32 precision return None. Prefer this for
+    /// near-singular or badly scaled transforms; `inverse` is cheaper otherwise.
+    #[must_use]
+    pub fn inverse_lu(&self) -> Option<Mat4x4> {
+        let scale = self.iter_elements().fold(0.0f32, |acc, v| acc.max(v.abs())) as f64;
+        let tolerance = scale * f32::EPSILON as f64;
+
+        let (lu, perm, _) = self.lu();
+        if scale == 0.0 || (0..4).any(|i| lu[i][i].abs() <= tolerance) {
+            return None;
+        }
+
+        let mut result = [[0.0; 4]; 4];
+        for col in 0..4 {
+            // Solve L * y = P * e_col, then U * x = y
+            let mut x = [0.0f64; 4];
+            for i in 0..4 {
+                let mut sum = if perm[i] == col { 1.0 } else { 0.0 };
+                for j in 0..i {
+                    sum -= lu[i][j] * x[j];
+                }
+                x[i] = sum;
+            }
+            for i in (0..4).rev() {
+                let mut sum = x[i];
+                for j in i + 1..4 {
+                    sum -= lu[i][j] * x[j];
+                }
+                x[i] = sum / lu[i][i];
+            }
+            for (row, value) in result.iter_mut().zip(x) {
+                row[col] = value as f32;
+            }
+        }
+
+        Some(Mat4x4 { m: result })
+    }
+
+    /// Raise the matrix to the integer power `n` by repeated squaring
+    ///
+    /// `powi(0)` is the identity. Negative powers invert the matrix first and
+    /// return None if it is not invertible; non-negative powers always succeed.
+    #[must_use]
+    pub fn powi(&self, n: i32) -> Option<Mat4x4> {
+        let mut base = if n < 0 { self.inverse()? } else { *self };
+        let mut exp = n.unsigned_abs();
+        let mut result = Mat4x4::identity();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        Some(result)
+    }
+
     /// Check if this matrix is approximately equal to another
     ///
-    /// Uses an epsilon value for floating point comparison
+    /// Uses an epsilon value for floating point comparison; see `approx_eq_eps`
+    /// to pick the tolerance
     #[must_use]
     pub fn approx_eq(&self, other: &Mat4x4) -> bool {
+        self.approx_eq_eps(other, EPSILON)
+    }
+
+    /// Check if every element differs from `other`'s by less than `epsilon`
+    #[must_use]
+    pub fn approx_eq_eps(&self, other: &Mat4x4, epsilon: f32) -> bool {
         for i in 0..4 {
             for j in 0..4 {
-                if (self.m[i][j] - other.m[i][j]).abs() >= EPSILON {
+                if (self.m[i][j] - other.m[i][j]).abs() >= epsilon {
                     return false;
                 }
             }
@@ -437,6 +827,53 @@ impl Mat4x4 {
         self.approx_eq(&Self::identity())
     }
 
+    /// Check if all elements are finite (neither NaN nor infinite)
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.m.iter().flatten().all(|v| v.is_finite())
+    }
+
+    /// Check if any element is NaN
+    #[must_use]
+    pub fn has_nan(&self) -> bool {
+        self.m.iter().flatten().any(|v| v.is_nan())
+    }
+
+    /// Check if this is an affine transform (last row ≈ `[0, 0, 0, 1]`)
+    ///
+    /// Translation, rotation, scale and shear matrices are affine; projection
+    /// matrices are not.
+    #[must_use]
+    pub fn is_affine(&self) -> bool {
+        let last = self.m[3];
+        last[0].abs() < EPSILON && last[1].abs() < EPSILON && last[2].abs() < EPSILON
+            && (last[3] - 1.0).abs() < EPSILON
+    }
+
+    /// Check if this is a perspective projection (last row has a nonzero in its first three entries)
+    ///
+    /// Such matrices produce a w that depends on the input position, so
+    /// transformed points need a perspective divide.
+    #[must_use]
+    pub fn is_perspective(&self) -> bool {
+        self.m[3][..3].iter().any(|v| v.abs() >= EPSILON)
+    }
+
+    /// Check if the upper-left 3x3 columns are unit length and mutually perpendicular
+    ///
+    /// Each column length and pairwise dot product is compared within `epsilon`.
+    /// Translation and the last row are ignored.
+    #[must_use]
+    pub fn is_orthonormal(&self, epsilon: f32) -> bool {
+        let [x, y, z] = self.basis_columns();
+        (x.magnitude_squared() - 1.0).abs() <= epsilon
+            && (y.magnitude_squared() - 1.0).abs() <= epsilon
+            && (z.magnitude_squared() - 1.0).abs() <= epsilon
+            && x.dot(y).abs() <= epsilon
+            && x.dot(z).abs() <= epsilon
+            && y.dot(z).abs() <= epsilon
+    }
+
     /// Get a specific element at (row, column)
     ///
     /// Returns None if indices are out of bounds
@@ -460,6 +897,304 @@ impl Mat4x4 {
             None
         }
     }
+
+    /// Get a row as a Vec4
+    ///
+    /// Panics if `index` is out of bounds
+    #[must_use]
+    pub fn row(&self, index: usize) -> Vec4 {
+        Vec4::from(self[index])
+    }
+
+    /// Get a column as a Vec4
+    ///
+    /// Panics if `index` is out of bounds
+    #[must_use]
+    pub fn col(&self, index: usize) -> Vec4 {
+        if index >= 4 {
+            panic!("Index out of bounds for Matrix4x4: column {}, but matrix has 4 columns", index);
+        }
+        Vec4::new(self.m[0][index], self.m[1][index], self.m[2][index], self.m[3][index])
+    }
+
+    /// Iterate over the rows as Vec4, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = Vec4> + '_ {
+        (0..4).map(|i| self.row(i))
+    }
+
+    /// Iterate over the columns as Vec4, left to right
+    pub fn cols(&self) -> impl Iterator<Item = Vec4> + '_ {
+        (0..4).map(|i| self.col(i))
+    }
+
+    /// Iterate over all 16 elements in row-major order
+    pub fn iter_elements(&self) -> impl Iterator<Item = f32> + '_ {
+        self.m.iter().flatten().copied()
+    }
+
+    /// Flatten to 16 elements in row-major order (the storage order)
+    #[must_use]
+    pub const fn to_row_major_array(&self) -> [f32; 16] {
+        let mut out = [0.0; 16];
+        let mut i = 0;
+        while i < 16 {
+            out[i] = self.m[i / 4][i % 4];
+            i += 1;
+        }
+        out
+    }
+
+    /// Flatten to 16 elements in column-major order
+    ///
+    /// This is the layout OpenGL-style APIs expect (e.g. `glUniformMatrix4fv`
+    /// with `transpose = false`); uploading the row-major array instead gives
+    /// the transposed matrix.
+    #[must_use]
+    pub const fn to_column_major_array(&self) -> [f32; 16] {
+        let mut out = [0.0; 16];
+        let mut i = 0;
+        while i < 16 {
+            out[i] = self.m[i % 4][i / 4];
+            i += 1;
+        }
+        out
+    }
+
+    /// Create a matrix from 16 elements in row-major order
+    #[must_use]
+    pub const fn from_row_major_array(elements: [f32; 16]) -> Self {
+        let mut m = [[0.0; 4]; 4];
+        let mut i = 0;
+        while i < 16 {
+            m[i / 4][i % 4] = elements[i];
+            i += 1;
+        }
+        Self { m }
+    }
+
+    /// Create a matrix from 16 elements in column-major order
+    #[must_use]
+    pub const fn from_column_major_array(elements: [f32; 16]) -> Self {
+        let mut m = [[0.0; 4]; 4];
+        let mut i = 0;
+        while i < 16 {
+            m[i % 4][i / 4] = elements[i];
+            i += 1;
+        }
+        Self { m }
+    }
+
+    /// Flatten to a Vec of 16 elements in row-major order
+    ///
+    /// Useful for storing the matrix as an `Any::ArrayFloat` on hosts that only
+    /// take float arrays.
+    #[must_use]
+    pub fn to_vec_f32(&self) -> Vec<f32> {
+        self.to_row_major_array().to_vec()
+    }
+
+    /// Create a matrix from a slice of exactly 16 elements in row-major order
+    pub fn try_from_f32_slice(elements: &[f32]) -> Result<Self, std::array::TryFromSliceError> {
+        <[f32; 16]>::try_from(elements).map(Self::from_row_major_array)
+    }
+
+    /// Get the right direction (+X basis vector) from the first column
+    ///
+    /// Uses the same right-handed convention as `look_at`, which stores the
+    /// camera basis in the columns of the rotation part.
+    #[must_use]
+    pub fn right(&self) -> Vec3 {
+        Vec3::new(self.m[0][0], self.m[1][0], self.m[2][0])
+    }
+
+    /// Get the up direction (+Y basis vector) from the second column
+    #[must_use]
+    pub fn up(&self) -> Vec3 {
+        Vec3::new(self.m[0][1], self.m[1][1], self.m[2][1])
+    }
+
+    /// Get the forward direction from the negated third column
+    ///
+    /// Forward is -Z in the right-handed convention used by `look_at`, so for a
+    /// view matrix this points from the eye towards the target.
+    #[must_use]
+    pub fn forward(&self) -> Vec3 {
+        Vec3::new(-self.m[0][2], -self.m[1][2], -self.m[2][2])
+    }
+
+    /// Get the translation stored in the last column
+    #[must_use]
+    pub fn translation_part(&self) -> Vec3 {
+        Vec3::new(self.m[0][3], self.m[1][3], self.m[2][3])
+    }
+
+    /// Get the axis scales as the lengths of the upper-left 3x3 columns
+    ///
+    /// A reflection (negative 3x3 determinant) is reported as a negative x scale,
+    /// matching `decompose`. This is much cheaper than a full decomposition.
+    #[must_use]
+    pub fn scale_part(&self) -> Vec3 {
+        let [x, y, z] = self.basis_columns();
+        let sign = if x.dot(y.cross(z)) < 0.0 { -1.0 } else { 1.0 };
+        Vec3::new(sign * x.magnitude(), y.magnitude(), z.magnitude())
+    }
+
+    /// Re-orthonormalize the upper-left 3x3 columns with Gram-Schmidt
+    ///
+    /// Use this to remove drift after accumulating many rotations. The x column
+    /// keeps its direction, y is made perpendicular to it, and z to both; any
+    /// scale is removed. Translation and the last row are preserved. If the
+    /// columns are linearly dependent the matrix is returned unchanged.
+    #[must_use]
+    pub fn orthonormalize(&self) -> Mat4x4 {
+        let [x, y, z] = self.basis_columns();
+        let x = x.normalize_or_zero();
+        let y = (y - x * x.dot(y)).normalize_or_zero();
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalize_or_zero();
+        if x.is_zero() || y.is_zero() || z.is_zero() {
+            return *self;
+        }
+
+        let mut result = *self;
+        for (c, axis) in [x, y, z].into_iter().enumerate() {
+            result.m[0][c] = axis.x;
+            result.m[1][c] = axis.y;
+            result.m[2][c] = axis.z;
+        }
+        result
+    }
+
+    /// Columns of the upper-left 3x3
+    fn basis_columns(&self) -> [Vec3; 3] {
+        let m = &self.m;
+        [
+            Vec3::new(m[0][0], m[1][0], m[2][0]),
+            Vec3::new(m[0][1], m[1][1], m[2][1]),
+            Vec3::new(m[0][2], m[1][2], m[2][2]),
+        ]
+    }
+
+    /// Decompose an affine transform into translation, rotation and scale
+    ///
+    /// The rotation is returned as a unit quaternion stored in a `Vec4` as `(x, y, z, w)`.
+    /// Reflections are folded into a negative x scale.
+    ///
+    /// Returns None if the matrix is not affine (last row is not `[0, 0, 0, 1]`)
+    /// or if any axis has zero scale.
+    #[must_use]
+    pub fn decompose(&self) -> Option<(Vec3, Vec4, Vec3)> {
+        if !self.is_affine() {
+            return None;
+        }
+
+        let translation = self.translation_part();
+        let scale = self.scale_part();
+        if scale.x.abs() < EPSILON || scale.y < EPSILON || scale.z < EPSILON {
+            return None;
+        }
+
+        let mut r = [[0.0; 3]; 3];
+        for (i, row) in r.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.m[i][j] / scale[j];
+            }
+        }
+
+        Some((translation, quat_from_rotation(&r), scale))
+    }
+
+    /// Compose an affine transform from translation, rotation and scale
+    ///
+    /// This is the inverse of `decompose`: the result applies scale, then
+    /// rotation, then translation. The rotation is a unit quaternion `(x, y, z, w)`.
+    #[must_use]
+    pub fn compose(translation: Vec3, rotation: Vec4, scale: Vec3) -> Self {
+        let r = quat_to_rotation(rotation);
+        Self {
+            m: [
+                [r[0][0] * scale.x, r[0][1] * scale.y, r[0][2] * scale.z, translation.x],
+                [r[1][0] * scale.x, r[1][1] * scale.y, r[1][2] * scale.z, translation.y],
+                [r[2][0] * scale.x, r[2][1] * scale.y, r[2][2] * scale.z, translation.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Blend between two affine transforms
+    ///
+    /// Translation and scale are interpolated linearly and rotation with
+    /// quaternion slerp, so intermediate results stay free of skew. This is
+    /// the correct way to blend animation poses.
+    ///
+    /// If either matrix cannot be decomposed (projective or zero scale), the
+    /// 16 elements are interpolated component-wise instead.
+    #[must_use]
+    pub fn lerp(&self, other: &Mat4x4, t: f32) -> Mat4x4 {
+        match (self.decompose(), other.decompose()) {
+            (Some((t0, r0, s0)), Some((t1, r1, s1))) => {
+                Self::compose(t0.lerp(t1, t), quat_slerp(r0, r1, t), s0.lerp(s1, t))
+            }
+            _ => *self + (*other - *self) * t,
+        }
+    }
+}
+
+// ============================================
+// Quaternion helpers (stored as Vec4: x, y, z, w)
+// ============================================
+
+/// Convert an orthonormal 3x3 rotation matrix into a unit quaternion
+fn quat_from_rotation(r: &[[f32; 3]; 3]) -> Vec4 {
+    let trace = r[0][0] + r[1][1] + r[2][2];
+
+    let q = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        Vec4::new((r[2][1] - r[1][2]) / s, (r[0][2] - r[2][0]) / s, (r[1][0] - r[0][1]) / s, 0.25 * s)
+    } else if r[0][0] > r[1][1] && r[0][0] > r[2][2] {
+        let s = (1.0 + r[0][0] - r[1][1] - r[2][2]).sqrt() * 2.0;
+        Vec4::new(0.25 * s, (r[0][1] + r[1][0]) / s, (r[0][2] + r[2][0]) / s, (r[2][1] - r[1][2]) / s)
+    } else if r[1][1] > r[2][2] {
+        let s = (1.0 + r[1][1] - r[0][0] - r[2][2]).sqrt() * 2.0;
+        Vec4::new((r[0][1] + r[1][0]) / s, 0.25 * s, (r[1][2] + r[2][1]) / s, (r[0][2] - r[2][0]) / s)
+    } else {
+        let s = (1.0 + r[2][2] - r[0][0] - r[1][1]).sqrt() * 2.0;
+        Vec4::new((r[0][2] + r[2][0]) / s, (r[1][2] + r[2][1]) / s, 0.25 * s, (r[1][0] - r[0][1]) / s)
+    };
+
+    q.normalize()
+}
+
+/// Convert a unit quaternion into a 3x3 rotation matrix
+fn quat_to_rotation(q: Vec4) -> [[f32; 3]; 3] {
+    let Vec4 { x, y, z, w } = q;
+    [
+        [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w)],
+        [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w)],
+        [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y)],
+    ]
+}
+
+/// Spherical linear interpolation between two unit quaternions along the shortest arc
+fn quat_slerp(a: Vec4, b: Vec4, t: f32) -> Vec4 {
+    let mut dot = a.dot(b);
+    let b = if dot < 0.0 {
+        dot = -dot;
+        -b
+    } else {
+        b
+    };
+
+    if dot > 1.0 - 1e-4 {
+        // Nearly identical rotations, normalized lerp is accurate and stable
+        return a.lerp(b, t).normalize();
+    }
+
+    let theta = dot.clamp(-1.0, 1.0).acos();
+    let sin_theta = theta.sin();
+    let wa = ((1.0 - t) * theta).sin() / sin_theta;
+    let wb = (t * theta).sin() / sin_theta;
+
+    (a * wa + b * wb).normalize()
 }
 
 // ============================================
@@ -615,16 +1350,39 @@ impl IndexMut<usize> for Mat4x4 {
     }
 }
 
+/// Prints one row per line as `{:7.3}` by default
+///
+/// Width and precision flags override the defaults (`{:10.5}`), and the
+/// alternate flag (`{:#}`) prints all rows on a single line instead.
 impl fmt::Display for Mat4x4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Matrix4x4[\n  [{:7.3}, {:7.3}, {:7.3}, {:7.3}]\n  [{:7.3}, {:7.3}, {:7.3}, {:7.3}]\n  [{:7.3}, {:7.3}, {:7.3}, {:7.3}]\n  [{:7.3}, {:7.3}, {:7.3}, {:7.3}]\n]",
-            self.m[0][0], self.m[0][1], self.m[0][2], self.m[0][3],
-            self.m[1][0], self.m[1][1], self.m[1][2], self.m[1][3],
-            self.m[2][0], self.m[2][1], self.m[2][2], self.m[2][3],
-            self.m[3][0], self.m[3][1], self.m[3][2], self.m[3][3],
-        )
+        if f.alternate() {
+            write!(f, "Matrix4x4[")?;
+            for (r, row) in self.m.iter().enumerate() {
+                write!(f, "{}[", if r > 0 { ", " } else { "" })?;
+                for (c, v) in row.iter().enumerate() {
+                    let sep = if c > 0 { ", " } else { "" };
+                    match f.precision() {
+                        Some(precision) => write!(f, "{}{:.*}", sep, precision, v)?,
+                        None => write!(f, "{}{}", sep, v)?,
+                    }
+                }
+                write!(f, "]")?;
+            }
+            return write!(f, "]");
+        }
+
+        let width = f.width().unwrap_or(7);
+        let precision = f.precision().unwrap_or(3);
+        writeln!(f, "Matrix4x4[")?;
+        for row in &self.m {
+            writeln!(
+                f,
+                "  [{:w$.p$}, {:w$.p$}, {:w$.p$}, {:w$.p$}]",
+                row[0], row[1], row[2], row[3], w = width, p = precision,
+            )?;
+        }
+        write!(f, "]")
     }
 }
 
@@ -640,6 +1398,35 @@ impl From<Mat4x4> for [[f32; 4]; 4] {
     }
 }
 
+/// glam matrices are column-major, so the rows here become its columns
+#[cfg(feature = "glam")]
+impl From<glam::Mat4> for Mat4x4 {
+    fn from(m: glam::Mat4) -> Self {
+        Self::new(m.transpose().to_cols_array_2d())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Mat4x4> for glam::Mat4 {
+    fn from(m: Mat4x4) -> Self {
+        Self::from_cols_array_2d(&m.m).transpose()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Matrix4<f32>> for Mat4x4 {
+    fn from(m: nalgebra::Matrix4<f32>) -> Self {
+        Self::new(std::array::from_fn(|row| std::array::from_fn(|col| m[(row, col)])))
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Mat4x4> for nalgebra::Matrix4<f32> {
+    fn from(m: Mat4x4) -> Self {
+        Self::from_fn(|row, col| m.m[row][col])
+    }
+}
+
 // ============================================
 // Tests
 // ============================================
@@ -700,4 +1487,402 @@ mod tests {
         let product = m1 * m2;
         assert!(product.is_identity());
     }
-}
\ No newline at end of file
+
+    /// Project a point on the view axis at distance `d` and return its NDC depth
+    fn depth_at(m: &Mat4x4, d: f32) -> f32 {
+        let clip = *m * Vec4::position(0.0, 0.0, -d);
+        clip.z / clip.w
+    }
+
+    #[test]
+    fn test_projection_depth_ranges() {
+        let (near, far) = (0.1, 100.0);
+        let fov = std::f32::consts::FRAC_PI_2;
+        let eps = 1e-4;
+
+        let gl = Mat4x4::perspective(fov, 1.0, near, far);
+        assert!((depth_at(&gl, near) + 1.0).abs() < eps);
+        assert!((depth_at(&gl, far) - 1.0).abs() < eps);
+
+        let zo = Mat4x4::perspective_zo(fov, 1.0, near, far);
+        assert!(depth_at(&zo, near).abs() < eps);
+        assert!((depth_at(&zo, far) - 1.0).abs() < eps);
+
+        let rz = Mat4x4::perspective_reversed_z(fov, 1.0, near, far);
+        assert!((depth_at(&rz, near) - 1.0).abs() < eps);
+        assert!(depth_at(&rz, far).abs() < eps);
+
+        let ortho_zo = Mat4x4::orthographic_zo(-1.0, 1.0, -1.0, 1.0, near, far);
+        assert!(depth_at(&ortho_zo, near).abs() < eps);
+        assert!((depth_at(&ortho_zo, far) - 1.0).abs() < eps);
+
+        let ortho_rz = Mat4x4::orthographic_reversed_z(-1.0, 1.0, -1.0, 1.0, near, far);
+        assert!((depth_at(&ortho_rz, near) - 1.0).abs() < eps);
+        assert!(depth_at(&ortho_rz, far).abs() < eps);
+    }
+
+    fn assert_orthonormal(m: &Mat4x4) {
+        let product = m.transpose() * *m;
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product.m[i][j] - expected).abs() < 1e-5);
+            }
+        }
+        assert!((m.determinant() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_decompose_compose() {
+        let m = Mat4x4::translation(1.0, 2.0, 3.0)
+            * Mat4x4::rotation_y(0.7)
+            * Mat4x4::scaling(2.0, 3.0, 4.0);
+        let (t, r, s) = m.decompose().unwrap();
+        assert!((t - Vec3::new(1.0, 2.0, 3.0)).magnitude() < 1e-5);
+        assert!((s - Vec3::new(2.0, 3.0, 4.0)).magnitude() < 1e-5);
+        assert!(r.is_normalized());
+
+        let rebuilt = Mat4x4::compose(t, r, s);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((rebuilt.m[i][j] - m.m[i][j]).abs() < 1e-5);
+            }
+        }
+
+        let projection = Mat4x4::perspective(1.0, 1.0, 0.1, 10.0);
+        assert!(projection.decompose().is_none());
+    }
+
+    #[test]
+    fn test_lerp_rotation() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        let a = Mat4x4::rotation_z(0.0);
+        let b = Mat4x4::rotation_z(FRAC_PI_2);
+        let mid = a.lerp(&b, 0.5);
+
+        assert_orthonormal(&mid);
+        let expected = Mat4x4::rotation_z(FRAC_PI_4);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((mid.m[i][j] - expected.m[i][j]).abs() < 1e-5);
+            }
+        }
+
+        // Translation and scale blend linearly
+        let c = Mat4x4::translation(2.0, 0.0, 0.0) * Mat4x4::uniform_scaling(3.0);
+        let blended = Mat4x4::identity().lerp(&c, 0.5);
+        let (t, _, s) = blended.decompose().unwrap();
+        assert!((t - Vec3::new(1.0, 0.0, 0.0)).magnitude() < 1e-5);
+        assert!((s - Vec3::new(2.0, 2.0, 2.0)).magnitude() < 1e-5);
+    }
+
+    #[test]
+    fn test_rows_cols_iter() {
+        let m = Mat4x4::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+
+        assert_eq!(m.iter_elements().sum::<f32>(), 136.0);
+        assert_eq!(m.iter_elements().nth(4), Some(5.0));
+
+        let mut rows = [[0.0; 4]; 4];
+        for (dst, row) in rows.iter_mut().zip(m.rows()) {
+            *dst = row.into();
+        }
+        let rebuilt = Mat4x4::new(rows);
+        assert_eq!(rebuilt, m);
+
+        assert_eq!(m.col(1), Vec4::new(2.0, 6.0, 10.0, 14.0));
+        let largest = m.cols().max_by(|a, b| a.magnitude().total_cmp(&b.magnitude()));
+        assert_eq!(largest, Some(m.col(3)));
+        assert_eq!(m.cols().count(), 4);
+    }
+
+    #[test]
+    fn test_finite_and_nan() {
+        let m = Mat4x4::translation(1.0, 2.0, 3.0);
+        assert!(m.is_finite());
+        assert!(!m.has_nan());
+
+        let mut nan = m;
+        nan.m[2][1] = f32::NAN;
+        assert!(!nan.is_finite());
+        assert!(nan.has_nan());
+
+        let mut inf = m;
+        inf.m[3][3] = f32::NEG_INFINITY;
+        assert!(!inf.is_finite());
+        assert!(!inf.has_nan());
+    }
+
+    #[test]
+    fn test_from_euler_translation_scale() {
+        let euler = Vec3::new(0.3, -1.1, 2.0);
+        let t = Vec3::new(4.0, -5.0, 6.0);
+        let s = Vec3::new(1.5, 2.0, 0.5);
+
+        let m = Mat4x4::from_euler_translation_scale(euler, t, s);
+        let manual = Mat4x4::translation(t.x, t.y, t.z)
+            * Mat4x4::rotation_z(euler.z)
+            * Mat4x4::rotation_y(euler.y)
+            * Mat4x4::rotation_x(euler.x)
+            * Mat4x4::scaling(s.x, s.y, s.z);
+        assert!(m.approx_eq(&manual));
+
+        // A different order gives a different result
+        let xyz = Mat4x4::translation(t.x, t.y, t.z)
+            * Mat4x4::rotation_x(euler.x)
+            * Mat4x4::rotation_y(euler.y)
+            * Mat4x4::rotation_z(euler.z)
+            * Mat4x4::scaling(s.x, s.y, s.z);
+        assert!(!m.approx_eq(&xyz));
+    }
+
+    #[test]
+    fn test_basis_directions() {
+        let view = Mat4x4::look_at((0.0, 0.0, 5.0), (0.0, 0.0, 0.0), (0.0, 1.0, 0.0));
+        assert!(view.forward().approx_eq(Vec3::new(0.0, 0.0, -1.0)));
+        assert!(view.right().approx_eq(Vec3::new(1.0, 0.0, 0.0)));
+        assert!(view.up().approx_eq(Vec3::new(0.0, 1.0, 0.0)));
+
+        let view = Mat4x4::look_at((1.0, 2.0, 3.0), (4.0, 2.0, 3.0), (0.0, 1.0, 0.0));
+        assert!(view.forward().approx_eq(Vec3::new(1.0, 0.0, 0.0)));
+        assert!(view.right().approx_eq(Vec3::new(0.0, 0.0, 1.0)));
+        assert!(view.up().approx_eq(Vec3::new(0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_lu_matches_cofactor() {
+        let m = Mat4x4::translation(1.0, -2.0, 3.0)
+            * Mat4x4::rotation_axis((0.0, 0.6, 0.8), 0.9)
+            * Mat4x4::scaling(2.0, 0.5, 3.0);
+
+        assert!((m.determinant_lu() - m.determinant()).abs() < 1e-5);
+        let lu = m.inverse_lu().unwrap();
+        let cofactor = m.inverse().unwrap();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((lu.m[i][j] - cofactor.m[i][j]).abs() < 1e-5);
+            }
+        }
+        assert!((m * lu).is_identity());
+    }
+
+    #[test]
+    fn test_lu_near_singular() {
+        // Small uniform scale: perfectly conditioned, but the determinant is tiny
+        let small = Mat4x4::scaling(0.005, 0.005, 0.005);
+        assert!(small.inverse().is_none());
+        let inv = small.inverse_lu().unwrap();
+        assert!((small * inv).is_identity());
+        assert!((small.determinant_lu() - 1.25e-7).abs() < 1e-12);
+
+        // Genuinely singular: two equal rows
+        let singular = Mat4x4::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        assert!(singular.inverse_lu().is_none());
+        assert_eq!(singular.determinant_lu(), 0.0);
+    }
+
+    #[test]
+    fn test_shear() {
+        let p = Vec4::new(1.0, 2.0, 3.0, 1.0);
+
+        assert_eq!(Mat4x4::shear_xy(0.5) * p, Vec4::new(2.0, 2.0, 3.0, 1.0));
+        assert_eq!(Mat4x4::shear_xz(0.5) * p, Vec4::new(2.5, 2.0, 3.0, 1.0));
+        assert_eq!(Mat4x4::shear_yx(2.0) * p, Vec4::new(1.0, 4.0, 3.0, 1.0));
+        assert_eq!(Mat4x4::shear_yz(-1.0) * p, Vec4::new(1.0, -1.0, 3.0, 1.0));
+        assert_eq!(Mat4x4::shear_zx(1.0) * p, Vec4::new(1.0, 2.0, 4.0, 1.0));
+        assert_eq!(Mat4x4::shear_zy(0.25) * p, Vec4::new(1.0, 2.0, 3.5, 1.0));
+
+        // Shears preserve volume
+        assert!((Mat4x4::shear_xy(3.0).determinant() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_display_formatting() {
+        let m = Mat4x4::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [1.0, 2.5, -3.0, 1.0],
+        ]);
+
+        let default = format!("{}", m);
+        assert!(default.starts_with("Matrix4x4[\n  [  1.000,   0.000,   0.000,   0.000]\n"));
+        assert!(default.ends_with("  [  1.000,   2.500,  -3.000,   1.000]\n]"));
+
+        let precise = format!("{:.1}", m);
+        assert!(precise.contains("\n  [    1.0,     2.5,    -3.0,     1.0]\n"));
+        let narrow = format!("{:4.0}", Mat4x4::identity());
+        assert!(narrow.contains("\n  [   1,    0,    0,    0]\n"));
+
+        assert_eq!(
+            format!("{:#}", m),
+            "Matrix4x4[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [1, 2.5, -3, 1]]"
+        );
+        assert_eq!(
+            format!("{:#.2}", Mat4x4::scaling(0.5, 0.5, 0.5)),
+            "Matrix4x4[[0.50, 0.00, 0.00, 0.00], [0.00, 0.50, 0.00, 0.00], \
+             [0.00, 0.00, 0.50, 0.00], [0.00, 0.00, 0.00, 1.00]]"
+        );
+    }
+
+    #[test]
+    fn test_translation_and_scale_parts() {
+        let m = Mat4x4::translation(-4.0, 0.5, 9.0)
+            * Mat4x4::rotation_x(1.1)
+            * Mat4x4::scaling(2.0, 0.5, 3.0);
+        assert!((m.translation_part() - Vec3::new(-4.0, 0.5, 9.0)).magnitude() < 1e-5);
+        assert!((m.scale_part() - Vec3::new(2.0, 0.5, 3.0)).magnitude() < 1e-5);
+
+        let mirrored = Mat4x4::rotation_z(0.3) * Mat4x4::scaling(-2.0, 1.0, 1.0);
+        assert!((mirrored.scale_part() - Vec3::new(-2.0, 1.0, 1.0)).magnitude() < 1e-5);
+        assert!((mirrored.scale_part() - mirrored.decompose().unwrap().2).magnitude() < 1e-5);
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let mut drifted = Mat4x4::translation(3.0, -1.0, 2.0) * Mat4x4::rotation_y(0.8);
+        drifted.m[0][0] *= 1.01;
+        drifted.m[1][0] += 0.02;
+        drifted.m[2][1] -= 0.015;
+        assert!(!drifted.is_orthonormal(1e-4));
+
+        let fixed = drifted.orthonormalize();
+        assert!(fixed.is_orthonormal(1e-5));
+        assert_orthonormal(&fixed);
+        assert!((fixed.translation_part() - Vec3::new(3.0, -1.0, 2.0)).magnitude() < 1e-6);
+        assert!((fixed.right() - drifted.right().normalize()).magnitude() < 1e-6);
+
+        assert!(Mat4x4::rotation_x(1.3).is_orthonormal(1e-5));
+        let flat = Mat4x4::scaling(1.0, 0.0, 1.0);
+        assert_eq!(flat.orthonormalize(), flat);
+    }
+
+    #[test]
+    fn test_flat_arrays() {
+        let m = Mat4x4::translation(7.0, 8.0, 9.0);
+        let rows = m.to_row_major_array();
+        let cols = m.to_column_major_array();
+        assert_eq!(rows[3], 7.0);
+        assert_eq!(&cols[12..15], &[7.0, 8.0, 9.0]);
+        assert_eq!(cols, m.transpose().to_row_major_array());
+        assert_eq!(rows, m.iter_elements().collect::<Vec<_>>().as_slice());
+
+        assert_eq!(Mat4x4::from_row_major_array(rows), m);
+        assert_eq!(Mat4x4::from_column_major_array(cols), m);
+
+        const FLAT: [f32; 16] = Mat4x4::identity().to_column_major_array();
+        assert_eq!(FLAT, Mat4x4::identity().to_row_major_array());
+        const { assert!(Mat4x4::ROW_MAJOR) };
+    }
+
+    #[test]
+    fn test_f32_vec_round_trip() {
+        let m = Mat4x4::translation(1.0, 2.0, 3.0) * Mat4x4::scaling(2.0, 2.0, 2.0);
+        let flat = m.to_vec_f32();
+        assert_eq!(flat.len(), 16);
+        assert_eq!(flat[3], 1.0);
+
+        let any = crate::Any::ArrayFloat(flat);
+        let crate::Any::ArrayFloat(values) = any else { unreachable!() };
+        assert_eq!(Mat4x4::try_from_f32_slice(&values).unwrap(), m);
+
+        assert!(Mat4x4::try_from_f32_slice(&values[..15]).is_err());
+        assert!(Mat4x4::try_from_f32_slice(&[0.0; 17]).is_err());
+    }
+
+    #[test]
+    fn test_affine_vs_perspective() {
+        let translation = Mat4x4::translation(1.0, 2.0, 3.0);
+        assert!(translation.is_affine());
+        assert!(!translation.is_perspective());
+
+        let projection = Mat4x4::perspective(std::f32::consts::FRAC_PI_2, 16.0 / 9.0, 0.1, 100.0);
+        assert!(!projection.is_affine());
+        assert!(projection.is_perspective());
+
+        // Orthographic projections keep the affine last row
+        let ortho = Mat4x4::orthographic(-1.0, 1.0, -1.0, 1.0, 0.1, 10.0);
+        assert!(ortho.is_affine());
+        assert!(!ortho.is_perspective());
+    }
+
+    #[test]
+    fn test_billboard() {
+        let position = Vec3::new(1.0, 0.0, -2.0);
+        let camera = Vec3::new(4.0, 3.0, 2.0);
+
+        let m = Mat4x4::billboard(position, camera, Vec3::up());
+        let to_camera = (camera - position).normalize();
+        assert!(m.forward().approx_eq(to_camera));
+        assert!(m.translation_part().approx_eq(position));
+        assert!(m.is_orthonormal(1e-5));
+        assert!(m.right().y.abs() < 1e-6);
+        assert!((m.determinant() - 1.0).abs() < 1e-5);
+
+        // Looking straight down still gives a valid basis
+        let top = Mat4x4::billboard(Vec3::zero(), Vec3::new(0.0, 5.0, 0.0), Vec3::up());
+        assert!(top.forward().approx_eq(Vec3::up()));
+        assert!(top.is_orthonormal(1e-5));
+
+        let tree = Mat4x4::billboard_axis(position, camera, Vec3::up());
+        assert!(tree.up().approx_eq(Vec3::up()));
+        assert!(tree.forward().approx_eq(Vec3::new(3.0, 0.0, 4.0).normalize()));
+        assert!(tree.forward().dot(to_camera) > 0.0);
+        assert!(tree.is_orthonormal(1e-5));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam_round_trip() {
+        let m = Mat4x4::translation(1.0, 2.0, 3.0);
+        let g: glam::Mat4 = m.into();
+        assert_eq!(g, glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0)));
+        assert_eq!(Mat4x4::from(g), m);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra_round_trip() {
+        let m = Mat4x4::translation(1.0, 2.0, 3.0);
+        let n: nalgebra::Matrix4<f32> = m.into();
+        assert_eq!(n, nalgebra::Matrix4::new_translation(&nalgebra::Vector3::new(1.0, 2.0, 3.0)));
+        assert_eq!(Mat4x4::from(n), m);
+    }
+
+    #[test]
+    fn test_approx_eq_eps() {
+        // Many small rotations drift further than the default epsilon allows
+        let step = Mat4x4::rotation_z(std::f32::consts::TAU / 1000.0);
+        let mut m = Mat4x4::identity();
+        for _ in 0..1000 {
+            m *= step;
+        }
+        assert!(!m.approx_eq(&Mat4x4::identity()));
+        assert!(m.approx_eq_eps(&Mat4x4::identity(), 1e-3));
+    }
+
+    #[test]
+    fn test_powi() {
+        let rot = Mat4x4::rotation_z(std::f32::consts::FRAC_PI_2);
+        assert!(rot.powi(4).unwrap().approx_eq(&Mat4x4::identity()));
+        assert!(rot.powi(2).unwrap().approx_eq(&(rot * rot)));
+        assert!(rot.powi(-1).unwrap().approx_eq(&rot.inverse().unwrap()));
+        assert!(rot.powi(-3).unwrap().approx_eq(&rot));
+        assert_eq!(rot.powi(0), Some(Mat4x4::identity()));
+
+        let singular = Mat4x4::scaling(1.0, 0.0, 1.0);
+        assert!(singular.powi(-2).is_none());
+        assert!(singular.powi(5).unwrap().approx_eq(&singular));
+    }
+}
@@ -0,0 +1,478 @@
+//! Pure-Rust stand-in for the host symbols
+//!
+//! Backs `Str`, `Arr` and `Var` with Rust allocations so they can be exercised
+//! without a real plugify host. Call [`install`] before touching any FFI type.
+//! The installed symbols are process-wide, so parallel tests that reload the
+//! plugin or swap symbols should serialize through [`lock_globals`].
+//!
+//! Enable the `mock` feature to use this from a plugin's own tests:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! plugify = { version = "1", features = ["mock"] }
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "mock")] {
+//! use plugify::{mock, Str};
+//!
+//! mock::install();
+//! assert_eq!(Str::from_str("x").to_string(), "x");
+//! # }
+//! ```
+//!
+//! Allocation counters are thread-local, so parallel tests don't see each
+//! other's allocations.
+
+use std::cell::Cell;
+use std::mem::{size_of, ManuallyDrop};
+use crate::*;
+
+/// Raw representation of `Str` and `Arr<T>`: three pointer-sized words
+type Raw = [usize; 3];
+
+thread_local! {
+    static LIVE_STRINGS: Cell<isize> = const { Cell::new(0) };
+    static LIVE_VECTORS: Cell<isize> = const { Cell::new(0) };
+    static FAIL_NEXT_ALLOC: Cell<bool> = const { Cell::new(false) };
+    static DESTROY_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Number of string buffers currently allocated by this thread
+#[must_use]
+pub fn live_strings() -> isize {
+    LIVE_STRINGS.with(Cell::get)
+}
+
+/// Number of vector buffers currently allocated by this thread
+#[must_use]
+pub fn live_vectors() -> isize {
+    LIVE_VECTORS.with(Cell::get)
+}
+
+/// Number of string and vector destroy calls made by this thread
+#[must_use]
+pub fn destroy_calls() -> usize {
+    DESTROY_CALLS.with(Cell::get)
+}
+
+/// Make the next non-empty string or vector allocation on this thread fail
+///
+/// The failed call follows the host contract in [`policy`](crate::policy): a
+/// construct, assign or append leaves the all-zero value and a reserve leaves
+/// the string unchanged. Calls that fit in the existing buffer don't allocate,
+/// so the failure stays armed for the next one.
+pub fn fail_next_alloc() {
+    FAIL_NEXT_ALLOC.with(|fail| fail.set(true));
+}
+
+fn track(counter: &'static std::thread::LocalKey<Cell<isize>>, delta: isize) {
+    counter.with(|c| c.set(c.get() + delta));
+}
+
+// ============================================
+// Strings: [data, size, capacity]
+// ============================================
+
+unsafe fn alloc_string(data: *const u8, size: usize) -> Raw {
+    if size == 0 || FAIL_NEXT_ALLOC.with(Cell::take) {
+        return [0; 3];
+    }
+    // SAFETY: caller guarantees `data` is valid for `size` bytes
+    let bytes = unsafe { std::slice::from_raw_parts(data, size) };
+    let mut buf = ManuallyDrop::new(bytes.to_vec());
+    track(&LIVE_STRINGS, 1);
+    [buf.as_mut_ptr() as usize, buf.len(), buf.capacity()]
+}
+
+unsafe fn free_string(raw: &mut Raw) {
+    if raw[0] != 0 {
+        // SAFETY: the buffer was produced by `alloc_string`
+        drop(unsafe { Vec::from_raw_parts(raw[0] as *mut u8, raw[1], raw[2]) });
+        track(&LIVE_STRINGS, -1);
+    }
+    *raw = [0; 3];
+}
+
+extern "C" fn construct_string(data: *const u8, size: usize) -> Str {
+    unsafe { std::mem::transmute::<Raw, Str>(alloc_string(data, size)) }
+}
+
+extern "C" fn destroy_string(str: *mut Str) {
+    DESTROY_CALLS.with(|calls| calls.set(calls.get() + 1));
+    unsafe { free_string(&mut *(str as *mut Raw)) }
+}
+
+extern "C" fn get_string_data(str: *const Str) -> *mut u8 {
+    unsafe { (*(str as *const Raw))[0] as *mut u8 }
+}
+
+extern "C" fn get_string_length(str: *const Str) -> usize {
+    unsafe { (*(str as *const Raw))[1] }
+}
+
+extern "C" fn assign_string(str: *mut Str, data: *const u8, size: usize) {
+    unsafe {
+        let raw = &mut *(str as *mut Raw);
+        // Reuse the buffer when it fits, like std::string::assign
+        if raw[0] != 0 && size <= raw[2] {
+            // `data` may point into this buffer, so copy with overlap allowed
+            std::ptr::copy(data, raw[0] as *mut u8, size);
+            raw[1] = size;
+            return;
+        }
+        // Copy first: `data` may point into the string being replaced
+        let new = alloc_string(data, size);
+        free_string(raw);
+        *raw = new;
+    }
+}
+
+extern "C" fn get_string_capacity(str: *const Str) -> usize {
+    unsafe { (*(str as *const Raw))[2] }
+}
+
+extern "C" fn reserve_string(str: *mut Str, capacity: usize) {
+    unsafe {
+        let raw = &mut *(str as *mut Raw);
+        if capacity <= raw[2] || FAIL_NEXT_ALLOC.with(Cell::take) {
+            return;
+        }
+        let mut buf = ManuallyDrop::new(Vec::<u8>::with_capacity(capacity));
+        if raw[1] > 0 {
+            std::ptr::copy_nonoverlapping(raw[0] as *const u8, buf.as_mut_ptr(), raw[1]);
+        }
+        let size = raw[1];
+        free_string(raw);
+        track(&LIVE_STRINGS, 1);
+        *raw = [buf.as_mut_ptr() as usize, size, buf.capacity()];
+    }
+}
+
+// ============================================
+// Vectors: [begin, end, capacity] pointers
+// ============================================
+
+unsafe fn alloc_vector<T: Clone>(data: *const T, size: usize) -> Raw {
+    if size == 0 || FAIL_NEXT_ALLOC.with(Cell::take) {
+        return [0; 3];
+    }
+    // SAFETY: caller guarantees `data` is valid for `size` elements
+    let items = unsafe { std::slice::from_raw_parts(data, size) };
+    let mut buf = ManuallyDrop::new(items.to_vec());
+    track(&LIVE_VECTORS, 1);
+    let begin = buf.as_mut_ptr() as usize;
+    [begin, begin + buf.len() * size_of::<T>(), begin + buf.capacity() * size_of::<T>()]
+}
+
+unsafe fn free_vector<T>(raw: &mut Raw) {
+    if raw[0] != 0 {
+        let len = (raw[1] - raw[0]) / size_of::<T>();
+        let cap = (raw[2] - raw[0]) / size_of::<T>();
+        // SAFETY: the buffer was produced by `alloc_vector::<T>`
+        drop(unsafe { Vec::from_raw_parts(raw[0] as *mut T, len, cap) });
+        track(&LIVE_VECTORS, -1);
+    }
+    *raw = [0; 3];
+}
+
+extern "C" fn construct_vector<T: ArrOps + Clone>(data: *const T, size: usize) -> Arr<T> {
+    unsafe { std::mem::transmute_copy(&alloc_vector(data, size)) }
+}
+
+extern "C" fn destroy_vector<T: ArrOps>(vec: *mut Arr<T>) {
+    DESTROY_CALLS.with(|calls| calls.set(calls.get() + 1));
+    unsafe { free_vector::<T>(&mut *(vec as *mut Raw)) }
+}
+
+extern "C" fn get_vector_size<T: ArrOps>(vec: *const Arr<T>) -> usize {
+    let raw = unsafe { &*(vec as *const Raw) };
+    (raw[1] - raw[0]) / size_of::<T>()
+}
+
+extern "C" fn get_vector_data<T: ArrOps>(vec: *const Arr<T>) -> *mut T {
+    unsafe { (*(vec as *const Raw))[0] as *mut T }
+}
+
+extern "C" fn assign_vector<T: ArrOps + Clone>(vec: *mut Arr<T>, data: *const T, size: usize) {
+    unsafe {
+        let raw = &mut *(vec as *mut Raw);
+        let cap = if raw[0] == 0 { 0 } else { (raw[2] - raw[0]) / size_of::<T>() };
+        if size != 0 && size <= cap {
+            // Reuse the buffer like std::vector::assign. Copy first: `data` may
+            // point into the vector being replaced
+            let items = std::slice::from_raw_parts(data, size).to_vec();
+            let len = (raw[1] - raw[0]) / size_of::<T>();
+            let mut buf = ManuallyDrop::new(Vec::from_raw_parts(raw[0] as *mut T, len, cap));
+            buf.clear();
+            buf.extend(items);
+            raw[1] = raw[0] + size * size_of::<T>();
+            return;
+        }
+        // Copy first: `data` may point into the vector being replaced
+        let new = alloc_vector(data, size);
+        free_vector::<T>(raw);
+        *raw = new;
+    }
+}
+
+extern "C" fn append_vector<T: ArrOps + Clone>(vec: *mut Arr<T>, data: *const T, size: usize) {
+    unsafe {
+        let raw = &mut *(vec as *mut Raw);
+        let len = (raw[1] - raw[0]) / size_of::<T>();
+        if raw[0] == 0 {
+            *raw = alloc_vector(data, size);
+            return;
+        }
+        if size != 0 && len + size > (raw[2] - raw[0]) / size_of::<T>() && FAIL_NEXT_ALLOC.with(Cell::take) {
+            free_vector::<T>(raw);
+            return;
+        }
+        let cap = (raw[2] - raw[0]) / size_of::<T>();
+        // Clone first: `data` may point into this vector, and reserving can move it
+        let items = std::slice::from_raw_parts(data, size).to_vec();
+        let mut buf = ManuallyDrop::new(Vec::from_raw_parts(raw[0] as *mut T, len, cap));
+        buf.extend(items);
+        let begin = buf.as_mut_ptr() as usize;
+        *raw = [begin, begin + buf.len() * size_of::<T>(), begin + buf.capacity() * size_of::<T>()];
+    }
+}
+
+// ============================================
+// Variants
+// ============================================
+
+extern "C" fn destroy_variant(variant: *mut Var) {
+    unsafe {
+        // The union payload lives at offset 0; dropping it runs the mocked
+        // destroy symbols above, which zero the payload so a second destroy is a no-op.
+        let data = variant as *mut u8;
+        match (*variant).current() {
+            Type::String => std::ptr::drop_in_place(data as *mut Str),
+            Type::ArrayBool => std::ptr::drop_in_place(data as *mut Arr<bool>),
+            Type::ArrayChar8 | Type::ArrayInt8 => std::ptr::drop_in_place(data as *mut Arr<i8>),
+            Type::ArrayChar16 | Type::ArrayUInt16 => std::ptr::drop_in_place(data as *mut Arr<u16>),
+            Type::ArrayInt16 => std::ptr::drop_in_place(data as *mut Arr<i16>),
+            Type::ArrayInt32 => std::ptr::drop_in_place(data as *mut Arr<i32>),
+            Type::ArrayInt64 => std::ptr::drop_in_place(data as *mut Arr<i64>),
+            Type::ArrayUInt8 => std::ptr::drop_in_place(data as *mut Arr<u8>),
+            Type::ArrayUInt32 => std::ptr::drop_in_place(data as *mut Arr<u32>),
+            Type::ArrayUInt64 => std::ptr::drop_in_place(data as *mut Arr<u64>),
+            Type::ArrayPointer => std::ptr::drop_in_place(data as *mut Arr<usize>),
+            Type::ArrayFloat => std::ptr::drop_in_place(data as *mut Arr<f32>),
+            Type::ArrayDouble => std::ptr::drop_in_place(data as *mut Arr<f64>),
+            Type::ArrayString => std::ptr::drop_in_place(data as *mut Arr<Str>),
+            Type::ArrayVector2 => std::ptr::drop_in_place(data as *mut Arr<Vec2>),
+            Type::ArrayVector3 => std::ptr::drop_in_place(data as *mut Arr<Vec3>),
+            Type::ArrayVector4 => std::ptr::drop_in_place(data as *mut Arr<Vec4>),
+            Type::ArrayMatrix4x4 => std::ptr::drop_in_place(data as *mut Arr<Mat4x4>),
+            _ => {}
+        }
+    }
+}
+
+// ============================================
+// Host environment: directories and plugin info
+// ============================================
+
+fn mock_str(s: &str) -> Str {
+    construct_string(s.as_ptr(), s.len())
+}
+
+extern "C" fn get_base_dir() -> Str { mock_str("/mock") }
+extern "C" fn get_extensions_dir() -> Str { mock_str("/mock/extensions") }
+extern "C" fn get_configs_dir() -> Str { mock_str("/mock/configs") }
+extern "C" fn get_data_dir() -> Str { mock_str("/mock/data") }
+extern "C" fn get_logs_dir() -> Str { mock_str("/mock/logs") }
+extern "C" fn get_cache_dir() -> Str { mock_str("/mock/cache") }
+
+extern "C" fn is_extension_loaded(_name: *const u8, _nsize: usize, _constraint: *const u8, _csize: usize) -> bool {
+    false
+}
+
+// Plugin info is derived from the handle so tests can tell inits apart
+extern "C" fn get_plugin_id(handle: usize) -> isize { handle as isize }
+extern "C" fn get_plugin_name(handle: usize) -> Str { mock_str(&format!("plugin{handle}")) }
+extern "C" fn get_plugin_description(_handle: usize) -> Str { mock_str("A mock plugin") }
+extern "C" fn get_plugin_version(_handle: usize) -> Str { mock_str("1.0.0") }
+extern "C" fn get_plugin_author(_handle: usize) -> Str { mock_str("mock") }
+extern "C" fn get_plugin_website(_handle: usize) -> Str { mock_str("") }
+extern "C" fn get_plugin_license(_handle: usize) -> Str { mock_str("MIT") }
+extern "C" fn get_plugin_location(_handle: usize) -> Str { mock_str("/mock/plugin") }
+extern "C" fn get_plugin_dependencies(_handle: usize) -> Arr<Str> { Arr::new() }
+
+// Generic dispatcher: echoes the first argument back
+extern "C" fn call_host_method(_name: *const u8, _size: usize, args: *const Arr<Var>) -> Var {
+    unsafe { (*args).as_slice().first().cloned().unwrap_or_default() }
+}
+
+extern "C" fn get_layout_descriptor() -> LayoutDescriptor {
+    LayoutDescriptor::native()
+}
+
+// ============================================
+// Installation
+// ============================================
+
+macro_rules! install_vector {
+    ($t:ty, $construct:ident, $destroy:ident, $size:ident, $data:ident, $assign:ident) => {
+        $construct(construct_vector::<$t> as *const () as usize);
+        $destroy(destroy_vector::<$t> as *const () as usize);
+        $size(get_vector_size::<$t> as *const () as usize);
+        $data(get_vector_data::<$t> as *const () as usize);
+        $assign(assign_vector::<$t> as *const () as usize);
+    };
+}
+
+/// Install the mock implementations of the string, vector and variant symbols
+///
+/// Every call installs them again, since `plugify_deinit` and `plugify_init`
+/// with a shorter table clear them. The symbols are process-wide, so tests
+/// that use FFI types should hold [`lock_globals`] while doing so.
+pub fn install() {
+    init_construct_string(construct_string as *const () as usize);
+    init_destroy_string(destroy_string as *const () as usize);
+    init_get_string_data(get_string_data as *const () as usize);
+    init_get_string_length(get_string_length as *const () as usize);
+    init_assign_string(assign_string as *const () as usize);
+    init_get_string_capacity(get_string_capacity as *const () as usize);
+    init_reserve_string(reserve_string as *const () as usize);
+
+    init_destroy_variant(destroy_variant as *const () as usize);
+
+    install_vector!(bool, init_construct_vector_bool, init_destroy_vector_bool, init_get_vector_size_bool, init_get_vector_data_bool, init_assign_vector_bool);
+    install_vector!(i8, init_construct_vector_char8, init_destroy_vector_char8, init_get_vector_size_char8, init_get_vector_data_char8, init_assign_vector_char8);
+    install_vector!(u16, init_construct_vector_char16, init_destroy_vector_char16, init_get_vector_size_char16, init_get_vector_data_char16, init_assign_vector_char16);
+    install_vector!(i8, init_construct_vector_int8, init_destroy_vector_int8, init_get_vector_size_int8, init_get_vector_data_int8, init_assign_vector_int8);
+    install_vector!(i16, init_construct_vector_int16, init_destroy_vector_int16, init_get_vector_size_int16, init_get_vector_data_int16, init_assign_vector_int16);
+    install_vector!(i32, init_construct_vector_int32, init_destroy_vector_int32, init_get_vector_size_int32, init_get_vector_data_int32, init_assign_vector_int32);
+    install_vector!(i64, init_construct_vector_int64, init_destroy_vector_int64, init_get_vector_size_int64, init_get_vector_data_int64, init_assign_vector_int64);
+    install_vector!(u8, init_construct_vector_uint8, init_destroy_vector_uint8, init_get_vector_size_uint8, init_get_vector_data_uint8, init_assign_vector_uint8);
+    install_vector!(u16, init_construct_vector_uint16, init_destroy_vector_uint16, init_get_vector_size_uint16, init_get_vector_data_uint16, init_assign_vector_uint16);
+    install_vector!(u32, init_construct_vector_uint32, init_destroy_vector_uint32, init_get_vector_size_uint32, init_get_vector_data_uint32, init_assign_vector_uint32);
+    install_vector!(u64, init_construct_vector_uint64, init_destroy_vector_uint64, init_get_vector_size_uint64, init_get_vector_data_uint64, init_assign_vector_uint64);
+    install_vector!(usize, init_construct_vector_pointer, init_destroy_vector_pointer, init_get_vector_size_pointer, init_get_vector_data_pointer, init_assign_vector_pointer);
+    install_vector!(f32, init_construct_vector_float, init_destroy_vector_float, init_get_vector_size_float, init_get_vector_data_float, init_assign_vector_float);
+    install_vector!(f64, init_construct_vector_double, init_destroy_vector_double, init_get_vector_size_double, init_get_vector_data_double, init_assign_vector_double);
+    install_vector!(Str, init_construct_vector_string, init_destroy_vector_string, init_get_vector_size_string, init_get_vector_data_string, init_assign_vector_string);
+    install_vector!(Var, init_construct_vector_variant, init_destroy_vector_variant, init_get_vector_size_variant, init_get_vector_data_variant, init_assign_vector_variant);
+    install_vector!(Vec2, init_construct_vector_vector2, init_destroy_vector_vector2, init_get_vector_size_vector2, init_get_vector_data_vector2, init_assign_vector_vector2);
+    install_vector!(Vec3, init_construct_vector_vector3, init_destroy_vector_vector3, init_get_vector_size_vector3, init_get_vector_data_vector3, init_assign_vector_vector3);
+    install_vector!(Vec4, init_construct_vector_vector4, init_destroy_vector_vector4, init_get_vector_size_vector4, init_get_vector_data_vector4, init_assign_vector_vector4);
+    install_vector!(Mat4x4, init_construct_vector_matrix4x4, init_destroy_vector_matrix4x4, init_get_vector_size_matrix4x4, init_get_vector_data_matrix4x4, init_assign_vector_matrix4x4);
+
+    init_append_vector_bool(append_vector::<bool> as *const () as usize);
+    init_append_vector_char8(append_vector::<i8> as *const () as usize);
+    init_append_vector_char16(append_vector::<u16> as *const () as usize);
+    init_append_vector_int8(append_vector::<i8> as *const () as usize);
+    init_append_vector_int16(append_vector::<i16> as *const () as usize);
+    init_append_vector_int32(append_vector::<i32> as *const () as usize);
+    init_append_vector_int64(append_vector::<i64> as *const () as usize);
+    init_append_vector_uint8(append_vector::<u8> as *const () as usize);
+    init_append_vector_uint16(append_vector::<u16> as *const () as usize);
+    init_append_vector_uint32(append_vector::<u32> as *const () as usize);
+    init_append_vector_uint64(append_vector::<u64> as *const () as usize);
+    init_append_vector_pointer(append_vector::<usize> as *const () as usize);
+    init_append_vector_float(append_vector::<f32> as *const () as usize);
+    init_append_vector_double(append_vector::<f64> as *const () as usize);
+    init_append_vector_string(append_vector::<Str> as *const () as usize);
+    init_append_vector_variant(append_vector::<Var> as *const () as usize);
+    init_append_vector_vector2(append_vector::<Vec2> as *const () as usize);
+    init_append_vector_vector3(append_vector::<Vec3> as *const () as usize);
+    init_append_vector_vector4(append_vector::<Vec4> as *const () as usize);
+    init_append_vector_matrix4x4(append_vector::<Mat4x4> as *const () as usize);
+}
+
+macro_rules! vector_table {
+    ($table:ident, $f:ident) => {
+        $table.extend([
+            $f::<bool> as *const () as usize,
+            $f::<i8> as *const () as usize,
+            $f::<u16> as *const () as usize,
+            $f::<i8> as *const () as usize,
+            $f::<i16> as *const () as usize,
+            $f::<i32> as *const () as usize,
+            $f::<i64> as *const () as usize,
+            $f::<u8> as *const () as usize,
+            $f::<u16> as *const () as usize,
+            $f::<u32> as *const () as usize,
+            $f::<u64> as *const () as usize,
+            $f::<usize> as *const () as usize,
+            $f::<f32> as *const () as usize,
+            $f::<f64> as *const () as usize,
+            $f::<Str> as *const () as usize,
+            $f::<Var> as *const () as usize,
+            $f::<Vec2> as *const () as usize,
+            $f::<Vec3> as *const () as usize,
+            $f::<Vec4> as *const () as usize,
+            $f::<Mat4x4> as *const () as usize,
+        ]);
+    };
+}
+
+/// Build the function table a host passes to `plugify_init`, in the same order
+///
+/// It includes the optional symbols, which are only read when `plugify_init`
+/// is passed [`OPTIONAL_SYMBOLS_VERSION`](crate::OPTIONAL_SYMBOLS_VERSION) or later.
+pub fn api_table() -> Vec<usize> {
+    let mut table = vec![
+        get_base_dir as *const () as usize,
+        get_extensions_dir as *const () as usize,
+        get_configs_dir as *const () as usize,
+        get_data_dir as *const () as usize,
+        get_logs_dir as *const () as usize,
+        get_cache_dir as *const () as usize,
+        is_extension_loaded as *const () as usize,
+        get_plugin_id as *const () as usize,
+        get_plugin_name as *const () as usize,
+        get_plugin_description as *const () as usize,
+        get_plugin_version as *const () as usize,
+        get_plugin_author as *const () as usize,
+        get_plugin_website as *const () as usize,
+        get_plugin_license as *const () as usize,
+        get_plugin_location as *const () as usize,
+        get_plugin_dependencies as *const () as usize,
+        construct_string as *const () as usize,
+        destroy_string as *const () as usize,
+        get_string_data as *const () as usize,
+        get_string_length as *const () as usize,
+        assign_string as *const () as usize,
+        destroy_variant as *const () as usize,
+    ];
+    vector_table!(table, construct_vector);
+    vector_table!(table, destroy_vector);
+    vector_table!(table, get_vector_size);
+    vector_table!(table, get_vector_data);
+    vector_table!(table, assign_vector);
+    // Optional symbols, in the order documented on `OPTIONAL_SYMBOLS_VERSION`
+    table.extend([
+        call_host_method as *const () as usize,
+        get_string_capacity as *const () as usize,
+        reserve_string as *const () as usize,
+        get_layout_descriptor as *const () as usize,
+    ]);
+    vector_table!(table, append_vector);
+    table
+}
+
+/// Install a fabricated `PluginInfo` so code reading `plugin_info()` can run without a host
+///
+/// Replaces any info already stored. `plugify_deinit` clears it again.
+pub fn set_plugin_info(info: PluginInfo) {
+    PLUGIN.reset();
+    let _ = PLUGIN.set(info);
+}
+
+/// Point `configs_path()` at `dir`, e.g. a temporary directory, without a host
+///
+/// Replaces any directory already stored. `plugify_deinit` clears it again.
+pub fn set_configs_dir(dir: &str) {
+    CONFIGS_DIR.reset();
+    let _ = CONFIGS_DIR.set(dir.to_string());
+}
+
+/// Serialize tests that touch the process-wide plugin globals or host symbols
+pub fn lock_globals() -> std::sync::MutexGuard<'static, ()> {
+    static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
@@ -1,7 +1,11 @@
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
-use crate::{import_symbol, vector::*, string::*, variant::*};
+use std::sync::atomic::{AtomicPtr, Ordering};
+use crate::{import_symbol, vector::*, string::*, variant::*, layout::*};
 
 import_symbol!(get_method_ptr, GET_METHOD_PTR, init_get_method_ptr, (name:*const u8, size:usize) -> usize);
+import_symbol!(unsafe call_host_method, CALL_HOST_METHOD, init_call_host_method, has_call_host_method, (name:*const u8, size:usize, args:*const Arr<Var>) -> Var);
 import_symbol!(get_base_dir, GET_BASE_DIR, init_get_base_dir, () -> Str);
 import_symbol!(get_extensions_dir, GET_EXTENSIONS_DIR, init_get_extensions_dir, () -> Str);
 import_symbol!(get_configs_dir, GET_CONFIGS_DIR, init_get_configs_dir, () -> Str);
@@ -20,8 +24,36 @@ import_symbol!(get_plugin_license, GET_PLUGIN_LICENSE, init_get_plugin_license,
 import_symbol!(get_plugin_location, GET_PLUGIN_LOCATION, init_get_plugin_location, (handle:PluginHandle) -> Str);
 import_symbol!(get_plugin_dependencies, GET_PLUGIN_DEPENDENCIES, init_get_plugin_dependencies, (handle:PluginHandle) -> Arr<Str>);
 
-// Constants
-const K_API_VERSION: i32 = 1;
+/// ABI version of the plugify C interface this crate was built against
+pub const API_VERSION: i32 = 1;
+
+/// Check if a host speaking `host_version` of the ABI can load this plugin
+#[must_use]
+pub const fn supports_api(host_version: i32) -> bool {
+    host_version >= API_VERSION
+}
+
+/// First host ABI version whose function table carries the optional symbols
+///
+/// The table `plugify_init` receives starts with the required symbols, in the
+/// order they are read there, ending with `assign_vector_matrix4x4`. From this
+/// version on it continues with the [`OPTIONAL_SYMBOLS`] optional ones, at
+/// these positions after the required ones:
+///
+/// | Index   | Symbol |
+/// |---------|--------|
+/// | 0       | `call_host_method` |
+/// | 1       | `get_string_capacity` |
+/// | 2       | `reserve_string` |
+/// | 3       | `get_layout_descriptor` |
+/// | 4 to 23 | `append_vector_<type>`, for the 20 vector element types in the same order as the other vector functions |
+///
+/// A host passes 0 for an entry it lacks, which leaves that symbol unset. Hosts
+/// speaking an older version never have these read, whatever the table length.
+pub const OPTIONAL_SYMBOLS_VERSION: i32 = 2;
+
+/// Number of optional symbols in the table of a host speaking [`OPTIONAL_SYMBOLS_VERSION`]
+pub const OPTIONAL_SYMBOLS: usize = 24;
 
 // Plugin handle type
 type PluginHandle = usize;
@@ -29,7 +61,7 @@ const _: () = assert!(size_of::<PluginHandle>() == size_of::<*const ()>());
 const _: () = assert!(align_of::<PluginHandle>() == align_of::<*const ()>());
 
 // Global plugin state
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
 pub struct PluginInfo {
     pub id: isize,
     pub name: String,
@@ -42,6 +74,80 @@ pub struct PluginInfo {
     pub dependencies: Vec<String>,
 }
 
+impl std::fmt::Display for PluginInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} v{} by {}", self.name, self.version, self.author)
+    }
+}
+
+impl PluginInfo {
+    /// Start building a `PluginInfo` with arbitrary field values
+    #[must_use]
+    pub fn builder() -> PluginInfoBuilder {
+        PluginInfoBuilder::default()
+    }
+}
+
+macro_rules! builder_field {
+    ($($name:ident: $t:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Set the plugin ", stringify!($name))]
+            #[must_use]
+            pub fn $name(mut self, $name: impl Into<$t>) -> Self {
+                self.info.$name = $name.into();
+                self
+            }
+        )*
+    };
+}
+
+/// Builder for a `PluginInfo` that did not come from the host
+///
+/// Unset fields are left empty and the id defaults to 0.
+///
+/// ```
+/// use plugify::PluginInfo;
+///
+/// let info = PluginInfo::builder().id(3).name("example").version("1.0.0").build();
+/// assert_eq!(info.name, "example");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PluginInfoBuilder {
+    info: PluginInfo,
+}
+
+impl PluginInfoBuilder {
+    /// Set the plugin id
+    #[must_use]
+    pub fn id(mut self, id: isize) -> Self {
+        self.info.id = id;
+        self
+    }
+
+    builder_field! {
+        name: String,
+        description: String,
+        version: String,
+        author: String,
+        website: String,
+        license: String,
+        location: String,
+    }
+
+    /// Set the dependency names
+    #[must_use]
+    pub fn dependencies<S: Into<String>>(mut self, dependencies: impl IntoIterator<Item = S>) -> Self {
+        self.info.dependencies = dependencies.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Finish building
+    #[must_use]
+    pub fn build(self) -> PluginInfo {
+        self.info
+    }
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct PluginContext {
@@ -67,17 +173,170 @@ impl PluginCallbacks {
     }
 }
 
-// Global statics (you may want to use a different pattern in real code)
-pub static BASE_DIR: OnceLock<String> = OnceLock::new();
-pub static EXTENSIONS_DIR: OnceLock<String> = OnceLock::new();
-pub static CONFIGS_DIR: OnceLock<String> = OnceLock::new();
-pub static DATA_DIR: OnceLock<String> = OnceLock::new();
-pub static LOGS_DIR: OnceLock<String> = OnceLock::new();
-pub static CACHE_DIR: OnceLock<String> = OnceLock::new();
-pub static PLUGIN: OnceLock<PluginInfo> = OnceLock::new();
-pub static HANDLE: OnceLock<PluginHandle> = OnceLock::new();
-pub static CONTEXT: OnceLock<PluginContext> = OnceLock::new();
-pub static CALLBACKS: OnceLock<PluginCallbacks> = OnceLock::new();
+/// A write-once cell that `plugify_deinit` can clear again
+///
+/// Works like `OnceLock`, but can be reset so a plugin that is deinitialized
+/// and initialized again in the same process picks up fresh values.
+///
+/// Values are leaked rather than dropped on reset, so references handed out
+/// by `get` stay valid for `'static`. Each init/deinit cycle leaks the few
+/// small values stored in the plugin globals.
+pub struct InitCell<T> {
+    ptr: AtomicPtr<T>,
+    _marker: PhantomData<*const T>,
+}
+
+// SAFETY: values are only shared by reference after being moved in,
+// which is sound for the same bounds as OnceLock
+unsafe impl<T: Send + Sync> Sync for InitCell<T> {}
+unsafe impl<T: Send> Send for InitCell<T> {}
+
+impl<T: 'static> InitCell<T> {
+    /// Create a new empty cell
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ptr: AtomicPtr::new(std::ptr::null_mut()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the stored value, or None if the cell is empty
+    #[must_use]
+    pub fn get(&self) -> Option<&'static T> {
+        // SAFETY: non-null pointers come from a leaked Box and are never freed
+        unsafe { self.ptr.load(Ordering::Acquire).as_ref() }
+    }
+
+    /// Store a value if the cell is empty
+    ///
+    /// Returns the value back if the cell was already set.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let new = Box::into_raw(Box::new(value));
+        match self.ptr.compare_exchange(std::ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => Ok(()),
+            // SAFETY: `new` was not published, so we still own it
+            Err(_) => Err(*unsafe { Box::from_raw(new) }),
+        }
+    }
+
+    /// Get the stored value, initializing it with `f` if the cell is empty
+    ///
+    /// If several threads race, `f` may run more than once but only one value is kept.
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &'static T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+        let _ = self.set(f());
+        self.get().expect("InitCell was set")
+    }
+
+    /// Empty the cell, leaking the previous value
+    pub(crate) fn reset(&self) {
+        self.ptr.store(std::ptr::null_mut(), Ordering::Release);
+    }
+}
+
+impl<T: 'static> Default for InitCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: std::fmt::Debug + 'static> std::fmt::Debug for InitCell<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("InitCell").field(&self.get()).finish()
+    }
+}
+
+// Global statics, cleared by `plugify_deinit`
+pub static BASE_DIR: InitCell<String> = InitCell::new();
+pub static EXTENSIONS_DIR: InitCell<String> = InitCell::new();
+pub static CONFIGS_DIR: InitCell<String> = InitCell::new();
+pub static DATA_DIR: InitCell<String> = InitCell::new();
+pub static LOGS_DIR: InitCell<String> = InitCell::new();
+pub static CACHE_DIR: InitCell<String> = InitCell::new();
+pub static PLUGIN: InitCell<PluginInfo> = InitCell::new();
+pub static HANDLE: InitCell<PluginHandle> = InitCell::new();
+pub static CONTEXT: InitCell<PluginContext> = InitCell::new();
+pub static CALLBACKS: InitCell<PluginCallbacks> = InitCell::new();
+
+/// Get the info the host reported for this plugin
+///
+/// Panics if called before `plugify_init`
+pub fn plugin_info() -> &'static PluginInfo {
+    PLUGIN.get().expect("PLUGIN not initialized")
+}
+
+/// Get the id the host assigned to this plugin
+///
+/// The id is signed because the host's `get_plugin_id` returns a signed value,
+/// which is passed through unchanged like `PluginInfo::id`.
+///
+/// Panics if called before `plugify_init`
+pub fn plugin_id() -> isize {
+    PLUGIN.get().expect("PLUGIN not initialized").id
+}
+
+/// Get the name of this plugin
+///
+/// Panics if called before `plugify_init`
+pub fn plugin_name() -> &'static str {
+    &PLUGIN.get().expect("PLUGIN not initialized").name
+}
+
+macro_rules! dir_path {
+    ($($(#[$doc:meta])* $name:ident => $cell:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            ///
+            /// Panics if called before `plugify_init`
+            pub fn $name() -> &'static Path {
+                Path::new($cell.get().expect(concat!(stringify!($cell), " not initialized")).as_str())
+            }
+        )*
+    };
+}
+
+dir_path! {
+    /// Get the host's base directory as a path
+    base_path => BASE_DIR,
+    /// Get the extensions directory as a path
+    extensions_path => EXTENSIONS_DIR,
+    /// Get the configs directory as a path
+    configs_path => CONFIGS_DIR,
+    /// Get the data directory as a path
+    data_path => DATA_DIR,
+    /// Get the logs directory as a path
+    logs_path => LOGS_DIR,
+    /// Get the cache directory as a path
+    cache_path => CACHE_DIR,
+}
+
+/// Get the path of `name` inside the configs directory
+///
+/// Panics if called before `plugify_init`
+pub fn config_file(name: &str) -> PathBuf {
+    configs_path().join(name)
+}
+
+/// Call a method exported by another plugin through the host's generic dispatcher
+///
+/// Arguments are passed as an `Arr<Var>` and the returned `Var` is converted back
+/// to `Any`. The host symbol has the signature
+/// `Var call_host_method(const char* name, size_t size, const plg::vector<plg::any>* args)`.
+///
+/// The dispatcher is optional. Hosts without it make every call return
+/// `Any::Invalid`; use `has_call_host_method` to tell that apart from a method
+/// that returned nothing.
+pub fn call_method(name: &str, args: &[Any]) -> Any {
+    if !has_call_host_method() {
+        return Any::Invalid;
+    }
+    let args = Arr::<Var>::from(args);
+    // SAFETY: `name` and `args` outlive the call and the host only reads through them
+    unsafe { call_host_method(name.as_ptr(), name.len(), &args) }.get()
+}
 
 pub fn on_plugin_start(func: fn()) {
     let callbacks = CALLBACKS.get_or_init(||PluginCallbacks::new());
@@ -94,6 +353,108 @@ pub fn on_plugin_end(func: fn()) {
     let _ = callbacks.end_callback.set(func);
 }
 
+/// Fixed-timestep accumulator for driving deterministic updates from `update(dt)`
+///
+/// A single `advance` yields at most `max_steps` steps; time beyond that is
+/// dropped, so a long stall doesn't turn into an endless catch-up.
+///
+/// ```
+/// # use plugify::FixedTimestep;
+/// let mut timestep = FixedTimestep::new(1.0 / 60.0);
+/// # let dt = 0.05;
+/// for () in timestep.advance(dt) {
+///     // step the simulation by `timestep.step()`
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedTimestep {
+    accumulator: f32,
+    step: f32,
+    max_steps: u32,
+}
+
+impl FixedTimestep {
+    /// Steps a single `advance` yields at most, unless set with `with_max_steps`
+    pub const DEFAULT_MAX_STEPS: u32 = 8;
+
+    /// Create an accumulator with an empty carry-over
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not a positive finite number.
+    #[must_use]
+    pub fn new(step: f32) -> Self {
+        assert!(step > 0.0 && step.is_finite(), "FixedTimestep step must be positive and finite");
+        Self { accumulator: 0.0, step, max_steps: Self::DEFAULT_MAX_STEPS }
+    }
+
+    /// Set how many steps a single `advance` may yield
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is 0.
+    #[must_use]
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        assert!(max_steps > 0, "FixedTimestep max_steps must be non-zero");
+        self.max_steps = max_steps;
+        self.accumulator = self.accumulator.min(self.limit());
+        self
+    }
+
+    /// Get the length of one fixed step, in seconds
+    #[must_use]
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Get the time carried over that has not yet been consumed, in seconds
+    #[must_use]
+    pub fn accumulator(&self) -> f32 {
+        self.accumulator
+    }
+
+    /// Get the most steps a single `advance` yields
+    #[must_use]
+    pub fn max_steps(&self) -> u32 {
+        self.max_steps
+    }
+
+    /// Add `dt` and yield once for every whole step now available
+    ///
+    /// Each item consumes one step from the accumulator; steps not pulled from
+    /// the iterator stay queued for the next call. Negative and NaN `dt` are
+    /// ignored, and the accumulator is capped at `max_steps` steps, which also
+    /// covers an infinite `dt`.
+    pub fn advance(&mut self, dt: f32) -> impl Iterator<Item = ()> + '_ {
+        let dt = if dt.is_nan() { 0.0 } else { dt.max(0.0) };
+        self.accumulator = (self.accumulator + dt).min(self.limit());
+        let mut remaining = self.max_steps;
+        std::iter::from_fn(move || {
+            if remaining > 0 && self.accumulator >= self.step {
+                remaining -= 1;
+                self.accumulator -= self.step;
+                Some(())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Get how far the accumulator is into the next step
+    ///
+    /// Useful for interpolating rendering between the last two fixed states.
+    /// The result is in `[0, 1)` once the iterator from `advance` has been
+    /// drained; steps still queued put it at 1 or above.
+    #[must_use]
+    pub fn alpha(&self) -> f32 {
+        self.accumulator / self.step
+    }
+
+    fn limit(&self) -> f32 {
+        self.step * self.max_steps as f32
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn plugify_init(
     data: *const usize,
@@ -101,8 +462,8 @@ pub extern "C" fn plugify_init(
     version: i32,
     handle: usize,
 ) -> i32 {
-    if version < K_API_VERSION {
-        return K_API_VERSION;
+    if !supports_api(version) {
+        return API_VERSION;
     }
 
     let api = unsafe { std::slice::from_raw_parts(data, len) };
@@ -243,7 +604,48 @@ pub extern "C" fn plugify_init(
     init_assign_vector_vector2(api[i]); i += 1;
     init_assign_vector_vector3(api[i]); i += 1;
     init_assign_vector_vector4(api[i]); i += 1;
-    init_assign_vector_matrix4x4(api[i]); // i += 1;
+    init_assign_vector_matrix4x4(api[i]); i += 1;
+
+    // Optional symbols, in the order documented on `OPTIONAL_SYMBOLS_VERSION`
+    let optional: [fn(usize); OPTIONAL_SYMBOLS] = [
+        init_call_host_method,
+        init_get_string_capacity,
+        init_reserve_string,
+        init_get_layout_descriptor,
+        init_append_vector_bool,
+        init_append_vector_char8,
+        init_append_vector_char16,
+        init_append_vector_int8,
+        init_append_vector_int16,
+        init_append_vector_int32,
+        init_append_vector_int64,
+        init_append_vector_uint8,
+        init_append_vector_uint16,
+        init_append_vector_uint32,
+        init_append_vector_uint64,
+        init_append_vector_pointer,
+        init_append_vector_float,
+        init_append_vector_double,
+        init_append_vector_string,
+        init_append_vector_variant,
+        init_append_vector_vector2,
+        init_append_vector_vector3,
+        init_append_vector_vector4,
+        init_append_vector_matrix4x4,
+    ];
+    let tail = if version >= OPTIONAL_SYMBOLS_VERSION { &api[i..] } else { &[] };
+    for (k, init) in optional.iter().enumerate() {
+        // Missing ones are still registered for `debug::symbol_status`
+        init(tail.get(k).copied().unwrap_or(0));
+    }
+
+    // Catch ABI mismatches before any FFI type crosses the boundary. The host
+    // only knows the version reply for refusing a plugin, so use that.
+    if let Err(err) = verify_layout() {
+        eprintln!("plugify: {err}, refusing to load");
+        crate::debug::reset_all();
+        return API_VERSION;
+    }
 
     // Get directory paths
     let _ = BASE_DIR.set(get_base_dir().to_string());
@@ -278,6 +680,27 @@ pub extern "C" fn plugify_init(
     0
 }
 
+/// Clear the plugin globals so `plugify_init` can run again
+///
+/// Called by the host before unloading or reloading the plugin. Registered
+/// callbacks are cleared too, so the host must call `plugify_main` again
+/// before the next `plugify_init`. Every imported host symbol is cleared as
+/// well, so none of the old host's pointers survive into the next init.
+#[unsafe(no_mangle)]
+pub extern "C" fn plugify_deinit() {
+    crate::debug::reset_all();
+    BASE_DIR.reset();
+    EXTENSIONS_DIR.reset();
+    CONFIGS_DIR.reset();
+    DATA_DIR.reset();
+    LOGS_DIR.reset();
+    CACHE_DIR.reset();
+    PLUGIN.reset();
+    HANDLE.reset();
+    CONTEXT.reset();
+    CALLBACKS.reset();
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn plugify_plugin_start() {
     let callbacks = CALLBACKS.get().expect("CALLBACKS not initialized");
@@ -302,4 +725,267 @@ pub extern "C" fn plugify_plugin_end() {
 #[unsafe(no_mangle)]
 pub extern "C" fn plugify_plugin_context() -> *const PluginContext {
     CONTEXT.get().expect("CONTEXT not initialized")
-}
\ No newline at end of file
+}
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_info_display() {
+        let info = PluginInfo {
+            id: 7,
+            name: "example".to_string(),
+            description: "An example plugin".to_string(),
+            version: "1.2.3".to_string(),
+            author: "untrustedmodders".to_string(),
+            website: String::new(),
+            license: "MIT".to_string(),
+            location: String::new(),
+            dependencies: Vec::new(),
+        };
+        assert_eq!(info.to_string(), "example v1.2.3 by untrustedmodders");
+    }
+
+    #[test]
+    fn test_plugin_info_builder() {
+        let info = PluginInfo::builder()
+            .id(7)
+            .name("example")
+            .version("1.2.3")
+            .author("untrustedmodders")
+            .dependencies(["core", "extra"])
+            .build();
+        assert_eq!(info.id, 7);
+        assert_eq!(info.to_string(), "example v1.2.3 by untrustedmodders");
+        assert_eq!(info.dependencies, ["core", "extra"]);
+        assert!(info.website.is_empty());
+
+        let _guard = crate::mock::lock_globals();
+        crate::mock::set_plugin_info(info);
+        assert_eq!(plugin_info().name, "example");
+        assert_eq!(plugin_id(), 7);
+
+        crate::mock::set_plugin_info(PluginInfo::builder().name("other").build());
+        assert_eq!(plugin_name(), "other");
+
+        plugify_deinit();
+        assert!(PLUGIN.get().is_none());
+    }
+
+    #[test]
+    fn test_api_version() {
+        assert!(supports_api(API_VERSION));
+        assert!(supports_api(API_VERSION + 1));
+        assert!(!supports_api(API_VERSION - 1));
+
+        // Rejected before touching the function table
+        assert_eq!(plugify_init(std::ptr::null(), 0, API_VERSION - 1, 0), API_VERSION);
+    }
+
+    #[test]
+    fn test_layout_mismatch_refuses_load() {
+        extern "C" fn wrong_layout() -> crate::LayoutDescriptor {
+            crate::LayoutDescriptor { variant_size: 24, ..crate::LayoutDescriptor::native() }
+        }
+
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+        let mut api = crate::mock::api_table();
+        let required = api.len() - OPTIONAL_SYMBOLS;
+        api[required + 3] = wrong_layout as *const () as usize;
+
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 1), API_VERSION);
+        assert!(PLUGIN.get().is_none());
+        assert!(crate::debug::symbol_status().iter().all(|&(_, ok)| !ok));
+    }
+
+    #[test]
+    fn test_plg_aliases() {
+        // The host-facing aliases name the same types the imported symbols use
+        let _: fn(PluginHandle) -> PlgString = get_plugin_name;
+        let _: fn(PluginHandle) -> PlgVector<PlgString> = get_plugin_dependencies;
+        let _: fn(PluginHandle) -> Arr<Str> = get_plugin_dependencies;
+    }
+
+    #[test]
+    fn test_init_deinit_init() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+        let api = crate::mock::api_table();
+
+        fn start() {}
+
+        on_plugin_start(start);
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 1), 0);
+        assert_eq!(plugin_id(), 1);
+        assert_eq!(plugin_name(), "plugin1");
+        assert_eq!(HANDLE.get(), Some(&1));
+        assert_eq!(CONFIGS_DIR.get().map(String::as_str), Some("/mock/configs"));
+        assert!(CONTEXT.get().unwrap().has_start);
+        let first_name = plugin_name();
+
+        plugify_deinit();
+        assert!(PLUGIN.get().is_none());
+        assert!(HANDLE.get().is_none());
+        assert!(CALLBACKS.get().is_none());
+
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 2), 0);
+        assert_eq!(plugin_id(), 2);
+        assert_eq!(plugin_name(), "plugin2");
+        assert_eq!(HANDLE.get(), Some(&2));
+        assert!(!CONTEXT.get().unwrap().has_start);
+
+        // References from the previous init stay valid
+        assert_eq!(first_name, "plugin1");
+
+        plugify_deinit();
+    }
+
+    #[test]
+    fn test_call_method() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+        let api = crate::mock::api_table();
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 1), 0);
+
+        let result = call_method("echo", &[Any::String("hello".to_string()), Any::Int32(2)]);
+        assert!(matches!(result, Any::String(s) if s == "hello"));
+        assert!(matches!(call_method("echo", &[]), Any::Invalid));
+
+        init_call_host_method(0);
+        assert!(!has_call_host_method());
+        assert!(matches!(call_method("echo", &[Any::Int32(2)]), Any::Invalid));
+
+        plugify_deinit();
+    }
+
+    #[test]
+    fn test_dir_paths() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+        let api = crate::mock::api_table();
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 1), 0);
+
+        assert_eq!(base_path(), Path::new("/mock"));
+        assert_eq!(logs_path(), Path::new("/mock/logs"));
+        assert_eq!(config_file("settings.json"), PathBuf::from("/mock/configs/settings.json"));
+
+        plugify_deinit();
+    }
+
+    #[test]
+    fn test_fixed_timestep() {
+        let mut timestep = FixedTimestep::new(0.25);
+
+        assert_eq!(timestep.advance(0.1).count(), 0);
+        assert_eq!(timestep.advance(0.2).count(), 1);
+        assert_eq!(timestep.advance(0.7).count(), 3);
+        assert_eq!(timestep.advance(0.0).count(), 0);
+        assert!((timestep.accumulator() - 0.0).abs() < 1e-6);
+
+        // Steps not consumed stay queued
+        assert_eq!(timestep.advance(0.6).take(1).count(), 1);
+        assert_eq!(timestep.advance(-1.0).count(), 1);
+        assert!((timestep.alpha() - 0.4).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_fixed_timestep_bounded() {
+        let mut timestep = FixedTimestep::new(1.0 / 60.0);
+        assert_eq!(timestep.advance(f32::INFINITY).count(), 8);
+        assert_eq!(timestep.advance(1e6).count(), 8);
+        assert_eq!(timestep.advance(f32::NAN).count(), 0);
+        assert!(timestep.accumulator().is_finite());
+
+        let mut timestep = FixedTimestep::new(0.25).with_max_steps(2);
+        assert_eq!(timestep.advance(1.0).count(), 2);
+        assert_eq!(timestep.advance(0.0).count(), 0);
+        assert!(timestep.alpha() < 1.0);
+    }
+
+    #[test]
+    fn test_symbol_status_after_partial_init() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+        // A full init first, so the truncated one below has pointers to clear
+        let api = crate::mock::api_table();
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 1), 0);
+        plugify_deinit();
+
+        // An older host: the optional symbols are not read, even if the table has more
+        let api = crate::mock::api_table();
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), API_VERSION, 1), 0);
+        let status = crate::debug::symbol_status();
+        let lookup = |name| status.iter().find(|&&(n, _)| n == name).map(|&(_, ok)| ok);
+        assert_eq!(lookup("get_plugin_name"), Some(true));
+        assert_eq!(lookup("no_such_symbol"), None);
+        for name in ["call_host_method", "get_string_capacity", "reserve_string", "get_layout_descriptor"] {
+            assert_eq!(lookup(name), Some(false), "{}", name);
+        }
+        let has_optional: [fn() -> bool; OPTIONAL_SYMBOLS] = [
+            has_call_host_method,
+            crate::has_string_capacity,
+            crate::has_reserve_string,
+            crate::has_layout_descriptor,
+            has_append_vector_bool,
+            has_append_vector_char8,
+            has_append_vector_char16,
+            has_append_vector_int8,
+            has_append_vector_int16,
+            has_append_vector_int32,
+            has_append_vector_int64,
+            has_append_vector_uint8,
+            has_append_vector_uint16,
+            has_append_vector_uint32,
+            has_append_vector_uint64,
+            has_append_vector_pointer,
+            has_append_vector_float,
+            has_append_vector_double,
+            has_append_vector_string,
+            has_append_vector_variant,
+            has_append_vector_vector2,
+            has_append_vector_vector3,
+            has_append_vector_vector4,
+            has_append_vector_matrix4x4,
+        ];
+        assert!(has_optional.iter().all(|has| !has()));
+        plugify_deinit();
+
+        let api = crate::mock::api_table();
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 1), 0);
+        assert!(crate::debug::symbol_status().iter().all(|&(_, ok)| ok));
+        plugify_deinit();
+    }
+
+    #[test]
+    fn test_deinit_clears_symbols() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+        let api = crate::mock::api_table();
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 1), 0);
+        assert!(has_append_vector_int32());
+
+        plugify_deinit();
+        assert!(crate::debug::symbol_status().iter().all(|&(_, ok)| !ok));
+
+        // Reload against a host without the optional symbols
+        let mut api = crate::mock::api_table();
+        let required = api.len() - OPTIONAL_SYMBOLS;
+        api[required..].fill(0);
+        assert_eq!(plugify_init(api.as_ptr(), api.len(), OPTIONAL_SYMBOLS_VERSION, 2), 0);
+        assert_eq!(plugin_name(), "plugin2");
+        assert!(!has_append_vector_int32());
+        assert!(!crate::has_layout_descriptor());
+
+        let mut arr = Arr::from(vec![1i32, 2]);
+        arr.extend_from_slice(&[3]);
+        assert_eq!(arr.as_slice(), &[1, 2, 3]);
+        drop(arr);
+
+        plugify_deinit();
+    }
+}
@@ -0,0 +1,144 @@
+//! Reporting host allocation failures
+//!
+//! # Host contract
+//!
+//! The C++ side must not let `std::bad_alloc` cross the FFI boundary, and the
+//! plugify API has no error channel for it. This crate therefore asks hosts to
+//! implement the following, which it cannot verify on its own:
+//!
+//! - `construct_string`, `assign_string`, `construct_vector_<type>`,
+//!   `assign_vector_<type>` and `append_vector_<type>` that fail to allocate
+//!   leave their result in the all-zero state of a value that owns no buffer,
+//!   releasing any previous contents.
+//! - `reserve_string` that fails leaves the string unchanged, as
+//!   `std::string::reserve` does.
+//!
+//! A successful call with non-empty data always leaves an owned buffer, so the
+//! wrappers check the returned fields for the all-zero state without another FFI
+//! call. A failed reserve shows as a capacity below the request, which is only
+//! checked when the host provides `get_string_capacity`. Either way the failure
+//! is reported through [`alloc_failed`] according to the policy set here. A host
+//! that aborts on allocation failure itself needs none of this.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// What to do when the host fails to allocate a `Str` or `Arr`
+///
+/// See the module docs for how the host signals a failed allocation. `Abort`
+/// is the default, since most hosts cannot recover from running out of memory;
+/// embedders that run plugin code inside a `catch_unwind` boundary can pick
+/// `Panic` instead.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AllocFailurePolicy {
+    /// Print a message and abort the process
+    #[default]
+    Abort,
+    /// Panic, so the failure can be caught with `catch_unwind`
+    Panic,
+}
+
+static POLICY: AtomicU8 = AtomicU8::new(AllocFailurePolicy::Abort as u8);
+
+/// Choose how allocation failures in `Str`/`Arr`/`Var` construction are reported
+///
+/// The policy is process-wide and takes effect for all threads.
+pub fn set_alloc_failure_policy(policy: AllocFailurePolicy) {
+    POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// Get the current allocation failure policy
+#[must_use]
+pub fn alloc_failure_policy() -> AllocFailurePolicy {
+    match POLICY.load(Ordering::Relaxed) {
+        1 => AllocFailurePolicy::Panic,
+        _ => AllocFailurePolicy::Abort,
+    }
+}
+
+/// Report that the host could not allocate `len` elements for `what`
+///
+/// Public only so the exported `vector_ops_traits!` macro can call it.
+#[doc(hidden)]
+#[cold]
+pub fn alloc_failed(what: &str, len: usize) -> ! {
+    match alloc_failure_policy() {
+        AllocFailurePolicy::Panic => panic!("host failed to allocate {what} of {len} elements"),
+        AllocFailurePolicy::Abort => {
+            eprintln!("plugify: host failed to allocate {what} of {len} elements, aborting");
+            std::process::abort()
+        }
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock, Any, Arr, Str, Var};
+
+    const ABORT_CHILD: &str = "PLUGIFY_ALLOC_ABORT_CHILD";
+
+    #[test]
+    fn test_panic_policy() {
+        let _guard = mock::lock_globals();
+        mock::install();
+        set_alloc_failure_policy(AllocFailurePolicy::Panic);
+        assert_eq!(alloc_failure_policy(), AllocFailurePolicy::Panic);
+
+        mock::fail_next_alloc();
+        assert!(std::panic::catch_unwind(|| Str::from("hello")).is_err());
+        mock::fail_next_alloc();
+        assert!(std::panic::catch_unwind(|| Arr::from(vec![1i32, 2, 3])).is_err());
+        mock::fail_next_alloc();
+        assert!(std::panic::catch_unwind(|| Var::new(&Any::ArrayFloat(vec![1.0]))).is_err());
+
+        // Empty values never allocate, so the failure stays armed for the next one
+        mock::fail_next_alloc();
+        assert!(Str::new().is_empty());
+        assert!(std::panic::catch_unwind(|| Str::from("hello")).is_err());
+        assert_eq!(Str::from("ok").as_str(), "ok");
+
+        // Allocating paths other than construction follow the same contract
+        let mut s = Str::from("a");
+        mock::fail_next_alloc();
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| s.set("longer"))).is_err());
+        assert!(s.is_empty());
+        mock::fail_next_alloc();
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| s.reserve(64))).is_err());
+        let mut arr = Arr::from(vec![1i32]);
+        mock::fail_next_alloc();
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| arr.set(&[1, 2, 3]))).is_err());
+        assert!(arr.is_empty());
+        let mut arr = Arr::from(vec![1i32]);
+        mock::fail_next_alloc();
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| arr.extend_from_slice(&[2, 3]))).is_err());
+        assert!(arr.is_empty());
+
+        set_alloc_failure_policy(AllocFailurePolicy::default());
+    }
+
+    #[test]
+    fn test_abort_policy() {
+        if std::env::var_os(ABORT_CHILD).is_some() {
+            let _guard = mock::lock_globals();
+            mock::install();
+            set_alloc_failure_policy(AllocFailurePolicy::Abort);
+            mock::fail_next_alloc();
+            // A panic would be caught here; only an abort ends the process
+            let _ = std::panic::catch_unwind(|| Str::from("hello"));
+            std::process::exit(0);
+        }
+
+        let status = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "policy::tests::test_abort_policy", "--nocapture"])
+            .env(ABORT_CHILD, "1")
+            .stderr(std::process::Stdio::null())
+            .status()
+            .unwrap();
+        assert!(!status.success());
+    }
+}
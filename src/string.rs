@@ -1,10 +1,15 @@
-use crate::import_symbol;
+use crate::{import_symbol, Arr};
 
 import_symbol!(construct_string, CONSTRUCT_STRING, init_construct_string, (data:*const u8, size:usize) -> Str);
 import_symbol!(destroy_string, DESTROY_STRING, init_destroy_string, (str:*mut Str) -> ());
 import_symbol!(get_string_data, GET_STRING_DATA, init_get_string_data, (str:*const Str) -> *mut u8);
 import_symbol!(get_string_length, GET_STRING_LENGTH, init_get_string_length, (str:*const Str) -> usize);
+// Host contract: like `std::string::assign(const char*, size_t)`, `assign_string`
+// must accept a `data` range that lies inside the string being assigned to.
+// `Str::trim_in_place` relies on it.
 import_symbol!(assign_string, ASSIGN_STRING, init_assign_string, (str:*mut Str, data:*const u8, size:usize) -> ());
+import_symbol!(unsafe get_string_capacity, GET_STRING_CAPACITY, init_get_string_capacity, has_string_capacity, (str:*const Str) -> usize);
+import_symbol!(unsafe reserve_string, RESERVE_STRING, init_reserve_string, has_reserve_string, (str:*mut Str, capacity:usize) -> ());
 
 /// FFI-compatible string type matching the memory layout of the C++ plg::string
 ///
@@ -40,6 +45,9 @@ pub struct Str {
 }
 const _: () = assert!(size_of::<Str>() == 3 * size_of::<*const ()>());
 
+/// Alias matching the C++ `plg::string` name used in host headers
+pub type PlgString = Str;
+
 impl std::fmt::Debug for Str {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("Str")
@@ -58,13 +66,29 @@ impl Str {
         construct_string("".as_ptr(), 0)
     }
 
+    /// Create a new empty Str with room for at least `capacity` bytes
+    ///
+    /// Subsequent `set`/`push_str` calls that fit in the capacity don't reallocate
+    /// on the host side. Like `String::with_capacity` this is only a hint: on
+    /// hosts without the optional `reserve_string` symbol it is the same as `new`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut s = Self::new();
+        s.reserve(capacity);
+        s
+    }
+
     /// Create a new Str from a string slice
     ///
     /// # Panics
     ///
-    /// May panic if the C++ allocation fails. The panic is safe - no resources will leak.
+    /// If the C++ allocation fails, panics or aborts according to the
+    /// [`AllocFailurePolicy`](crate::AllocFailurePolicy). The panic is safe - no resources will leak.
     pub fn from_str(s: &str) -> Self {
-        construct_string(s.as_ptr(), s.len())
+        let str = construct_string(s.as_ptr(), s.len());
+        if !s.is_empty() && str.is_unallocated() {
+            crate::policy::alloc_failed("string", s.len());
+        }
+        str
     }
 
     /// Get the string as a borrowed &str (zero-copy view)
@@ -200,6 +224,9 @@ impl Str {
     }
 
     /// Get the length of the string in bytes
+    ///
+    /// This is not the number of characters: multibyte UTF-8 characters count
+    /// once per byte. Use `char_count()` for the number of characters.
     #[must_use]
     pub fn len(&self) -> usize {
         get_string_length(self)
@@ -211,14 +238,256 @@ impl Str {
         self.len() == 0
     }
 
+    /// Check for the all-zero value of a string that never allocated
+    fn is_unallocated(&self) -> bool {
+        self.data == 0 && self.size == 0 && self.cap == 0
+    }
+
+    /// Get the number of bytes the string can hold without reallocating
+    ///
+    /// Hosts without the optional `get_string_capacity` symbol can't report it,
+    /// so this falls back to `len()`.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        if !has_string_capacity() {
+            return self.len();
+        }
+        // SAFETY: `self` is a live string for the duration of the call
+        unsafe { get_string_capacity(self) }
+    }
+
+    /// Reserve room for at least `additional` more bytes
+    ///
+    /// Does nothing on hosts without the optional `reserve_string` symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`. If the host fails to
+    /// allocate, panics or aborts according to the
+    /// [`AllocFailurePolicy`](crate::AllocFailurePolicy); this is only detected
+    /// on hosts that also provide `get_string_capacity`.
+    pub fn reserve(&mut self, additional: usize) {
+        let capacity = self.len().checked_add(additional).expect("capacity overflow");
+        if additional > 0 && has_reserve_string() {
+            // SAFETY: `self` is a live string we hold exclusively
+            unsafe { reserve_string(self, capacity) }
+            if has_string_capacity() && self.capacity() < capacity {
+                crate::policy::alloc_failed("string", capacity);
+            }
+        }
+    }
+
+    /// Get the number of characters (Unicode scalar values) in the string
+    ///
+    /// This walks the whole string, unlike `len()` which returns the byte length.
+    #[must_use]
+    pub fn char_count(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Iterate over the characters of the string
+    pub fn chars(&self) -> std::str::Chars<'_> {
+        self.as_str().chars()
+    }
+
+    /// Iterate over the characters of the string and their byte offsets
+    pub fn char_indices(&self) -> std::str::CharIndices<'_> {
+        self.as_str().char_indices()
+    }
+
+    /// Iterate over the characters of the string from last to first
+    pub fn chars_rev(&self) -> std::iter::Rev<std::str::Chars<'_>> {
+        self.as_str().chars().rev()
+    }
+
+    /// Get the number of extended grapheme clusters (user-perceived characters)
+    ///
+    /// Requires the `unicode` feature.
+    #[cfg(feature = "unicode")]
+    #[must_use]
+    pub fn grapheme_count(&self) -> usize {
+        self.graphemes().count()
+    }
+
+    /// Iterate over the extended grapheme clusters of the string
+    ///
+    /// A cluster such as `"e\u{301}"` or a flag emoji is yielded as one `&str`,
+    /// which is what cursor movement should step over. Requires the `unicode` feature.
+    #[cfg(feature = "unicode")]
+    pub fn graphemes(&self) -> unicode_segmentation::Graphemes<'_> {
+        unicode_segmentation::UnicodeSegmentation::graphemes(self.as_str(), true)
+    }
+
+    /// Create a Str from UTF-16 code units
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `units` contains an unpaired surrogate.
+    pub fn from_utf16(units: &[u16]) -> Result<Str, std::string::FromUtf16Error> {
+        Ok(Self::from_str(&String::from_utf16(units)?))
+    }
+
+    /// Encode the string as UTF-16 code units
+    #[must_use]
+    pub fn to_utf16(&self) -> Vec<u16> {
+        self.as_str().encode_utf16().collect()
+    }
+
     /// Set the string to a new value, replacing the previous contents
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// The C++ implementation must not throw exceptions. If allocation fails,
-    /// the process will abort.
+    /// If the host fails to allocate, panics or aborts according to the
+    /// [`AllocFailurePolicy`](crate::AllocFailurePolicy), leaving the string empty.
     pub fn set(&mut self, s: &str) {
         assign_string(self, s.as_ptr(), s.len());
+        if !s.is_empty() && self.is_unallocated() {
+            crate::policy::alloc_failed("string", s.len());
+        }
+    }
+
+    /// Append a string slice to the end of this string
+    ///
+    /// A convenience wrapper: the host API can only replace contents, so this
+    /// copies the concatenation into a Rust `String` and assigns it back. Each
+    /// call costs O(len), and building a string piece by piece is O(n²); to
+    /// build one from many pieces, assemble a `String` first and `set` it once.
+    pub fn push_str(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+        let mut buf = String::with_capacity(self.len() + s.len());
+        buf.push_str(self.as_str());
+        buf.push_str(s);
+        self.set(&buf);
+    }
+
+    /// Insert a character at byte index `idx`
+    ///
+    /// Like `push_str`, this reassigns the whole string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length or not on a char boundary.
+    pub fn insert(&mut self, idx: usize, c: char) {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Insert a string slice at byte index `idx`
+    ///
+    /// Like `push_str`, this reassigns the whole string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length or not on a char boundary.
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        let current = self.as_str();
+        assert!(current.is_char_boundary(idx), "insertion index {idx} is not a char boundary");
+        if s.is_empty() {
+            return;
+        }
+        let mut buf = String::with_capacity(current.len() + s.len());
+        buf.push_str(&current[..idx]);
+        buf.push_str(s);
+        buf.push_str(&current[idx..]);
+        self.set(&buf);
+    }
+
+    /// Remove and return the character starting at byte index `idx`
+    ///
+    /// Like `push_str`, this reassigns the whole string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not on a char boundary or is at the end of the string.
+    pub fn remove(&mut self, idx: usize) -> char {
+        let current = self.as_str();
+        assert!(current.is_char_boundary(idx), "removal index {idx} is not a char boundary");
+        let c = current[idx..].chars().next().expect("cannot remove a char from the end of a string");
+        let mut buf = String::with_capacity(current.len() - c.len_utf8());
+        buf.push_str(&current[..idx]);
+        buf.push_str(&current[idx + c.len_utf8()..]);
+        self.set(&buf);
+        c
+    }
+
+    /// Create a new Str from `parts` separated by `sep`
+    ///
+    /// The total length is computed up front, so the pieces are gathered into a
+    /// single buffer and handed to the host in one allocation.
+    #[must_use]
+    pub fn from_arr_joined(parts: &Arr<Str>, sep: &str) -> Str {
+        let parts = parts.as_slice();
+        let total = parts.iter().map(Str::len).sum::<usize>()
+            + sep.len() * parts.len().saturating_sub(1);
+        let mut buf = String::with_capacity(total);
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                buf.push_str(sep);
+            }
+            buf.push_str(part.as_str());
+        }
+        debug_assert_eq!(buf.len(), total);
+        Self::from_str(&buf)
+    }
+
+    /// Create a new Str with leading and trailing whitespace removed
+    #[must_use]
+    pub fn trimmed(&self) -> Str {
+        Self::from_str(self.trim())
+    }
+
+    /// Create a new Str with leading whitespace removed
+    #[must_use]
+    pub fn trim_start_owned(&self) -> Str {
+        Self::from_str(self.trim_start())
+    }
+
+    /// Create a new Str with trailing whitespace removed
+    #[must_use]
+    pub fn trim_end_owned(&self) -> Str {
+        Self::from_str(self.trim_end())
+    }
+
+    /// Remove leading and trailing whitespace in place
+    ///
+    /// The trimmed range is assigned straight from the string's own buffer,
+    /// so no intermediate Rust allocation is made. This needs a host whose
+    /// `assign_string` handles an overlapping source, as `std::string::assign` does.
+    pub fn trim_in_place(&mut self) {
+        let s = self.as_str();
+        let trimmed = s.trim();
+        if trimmed.len() == s.len() {
+            return;
+        }
+        let (ptr, len) = (trimmed.as_ptr(), trimmed.len());
+        // Aliased source, allowed by the host contract on `assign_string`
+        assign_string(self, ptr, len);
+    }
+
+    /// Check if the string equals `other`, ignoring ASCII case
+    #[must_use]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Check if the string starts with `prefix`, ignoring ASCII case
+    #[must_use]
+    pub fn starts_with_ignore_case(&self, prefix: &str) -> bool {
+        self.as_bytes()
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix.as_bytes()))
+    }
+
+    /// Check if the string contains `needle`, ignoring ASCII case
+    ///
+    /// An empty needle is always contained.
+    #[must_use]
+    pub fn contains_ignore_case(&self, needle: &str) -> bool {
+        needle.is_empty()
+            || self.as_bytes()
+                .windows(needle.len())
+                .any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
     }
 
     /// Destroy the string (manual cleanup)
@@ -228,10 +497,58 @@ impl Str {
     pub fn destroy(&mut self) {
         destroy_string(self);
     }
+
+    /// Create a Str containing `s` repeated `n` times
+    ///
+    /// The result is assembled first and handed to the host in a single allocation.
+    #[must_use]
+    pub fn repeat(s: &str, n: usize) -> Self {
+        Self::from_str(&s.repeat(n))
+    }
+
+    /// Pad the string on the left with `fill` up to `width` characters
+    ///
+    /// Width is measured in `char`s, not bytes. Strings already at least
+    /// `width` characters long are returned unchanged.
+    #[must_use]
+    pub fn pad_left(&self, width: usize, fill: char) -> Str {
+        self.pad(width, fill, true)
+    }
+
+    /// Pad the string on the right with `fill` up to `width` characters
+    ///
+    /// Width is measured in `char`s, not bytes. Strings already at least
+    /// `width` characters long are returned unchanged.
+    #[must_use]
+    pub fn pad_right(&self, width: usize, fill: char) -> Str {
+        self.pad(width, fill, false)
+    }
+
+    fn pad(&self, width: usize, fill: char, left: bool) -> Str {
+        let s = self.as_str();
+        let missing = width.saturating_sub(s.chars().count());
+        if missing == 0 {
+            return self.clone();
+        }
+        let mut buf = String::with_capacity(s.len() + missing * fill.len_utf8());
+        if !left {
+            buf.push_str(s);
+        }
+        buf.extend(std::iter::repeat_n(fill, missing));
+        if left {
+            buf.push_str(s);
+        }
+        Self::from_str(&buf)
+    }
 }
 
 impl Drop for Str {
     fn drop(&mut self) {
+        // All-zero words are an empty short string that owns no heap buffer, so
+        // the host destroy would be a no-op. Skip the FFI call.
+        if self.is_unallocated() {
+            return;
+        }
         self.destroy();
     }
 }
@@ -307,6 +624,24 @@ impl std::fmt::Display for Str {
     }
 }
 
+/// Appends by reassigning the whole string, like `push_str`
+///
+/// Each `write!` formats the current contents and the new text into one Rust
+/// `String` and assigns it once; it does not write into the host buffer.
+impl std::fmt::Write for Str {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> std::fmt::Result {
+        let mut buf = String::from(self.as_str());
+        buf.write_fmt(args)?;
+        self.set(&buf);
+        Ok(())
+    }
+}
+
 impl std::hash::Hash for Str {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state);
@@ -323,4 +658,252 @@ impl Ord for Str {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.as_str().cmp(other.as_str())
     }
-}
\ No newline at end of file
+}
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    #[test]
+    fn test_pad() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let s = Str::from_str("abc");
+        assert_eq!(s.pad_left(5, ' '), "  abc");
+        assert_eq!(s.pad_right(5, '.'), "abc..");
+        assert_eq!(s.pad_left(2, ' '), "abc");
+
+        // Width counts chars, not bytes
+        let s = Str::from_str("äöü");
+        assert_eq!(s.pad_right(5, '·'), "äöü··");
+    }
+
+    #[test]
+    fn test_repeat() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        assert_eq!(Str::repeat("ab", 3), "ababab");
+        assert!(Str::repeat("ab", 0).is_empty());
+    }
+
+    #[test]
+    fn test_char_count() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let s = Str::from_str("héllo→");
+        assert_eq!(s.len(), 9);
+        assert_eq!(s.char_count(), 6);
+        assert_eq!(s.chars().nth(1), Some('é'));
+        assert_eq!(s.char_indices().last(), Some((6, '→')));
+    }
+
+    #[test]
+    fn test_mock_round_trip() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let s = Str::from_str("x");
+        assert_eq!(s.to_string(), "x");
+        assert_eq!(mock::live_strings(), 1);
+        drop(s);
+        assert_eq!(mock::live_strings(), 0);
+
+        let ints = crate::Arr::from_slice(&[1i64, -2, 3]);
+        assert_eq!(ints.to_vec(), vec![1, -2, 3]);
+    }
+
+    #[test]
+    fn test_utf16_round_trip() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        // 'é' is in the BMP, '😀' needs a surrogate pair
+        let s = Str::from_str("é😀");
+        let units = s.to_utf16();
+        assert_eq!(units, vec![0x00E9, 0xD83D, 0xDE00]);
+        assert_eq!(Str::from_utf16(&units).unwrap(), "é😀");
+
+        assert!(Str::from_utf16(&[0xD83D, 0x0041]).is_err());
+    }
+
+    #[test]
+    fn test_format_into_str() {
+        use std::fmt::Write;
+
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut s = Str::from_str("id");
+        write!(s, "={}, name={:?}", 42, "x").unwrap();
+        assert_eq!(s, "id=42, name=\"x\"");
+        assert_eq!(mock::live_strings(), 1);
+
+        let formatted: Str = crate::plg_format!("x={}", 42);
+        assert_eq!(formatted, "x=42");
+    }
+
+    #[test]
+    fn test_trim() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let padded = Str::from("  \t hello world \n ");
+        assert_eq!(padded.trimmed(), "hello world");
+        assert_eq!(padded.trim_start_owned(), "hello world \n ");
+        assert_eq!(padded.trim_end_owned(), "  \t hello world");
+
+        let mut s = padded.clone();
+        s.trim_in_place();
+        assert_eq!(s, "hello world");
+        assert_eq!(s.len(), 11);
+
+        let mut blank = Str::from("   ");
+        blank.trim_in_place();
+        assert!(blank.is_empty());
+    }
+
+    #[test]
+    fn test_chars_rev() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let s = Str::from("ab→c");
+        assert_eq!(s.chars_rev().collect::<String>(), "c→ba");
+        assert_eq!(Str::new().chars_rev().count(), 0);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_graphemes() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        // "e" + combining acute accent is one grapheme but two chars
+        let s = Str::from("cafe\u{301}!");
+        assert_eq!(s.char_count(), 6);
+        assert_eq!(s.grapheme_count(), 5);
+        assert_eq!(s.graphemes().nth(3), Some("e\u{301}"));
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut s = Str::with_capacity(100);
+        assert_eq!(s.len(), 0);
+        assert!(s.capacity() >= 100);
+
+        let cap = s.capacity();
+        s.push_str("hello");
+        s.push_str(", world");
+        assert_eq!(s, "hello, world");
+        assert_eq!(s.capacity(), cap);
+
+        s.reserve(200);
+        assert!(s.capacity() >= s.len() + 200);
+        assert_eq!(s, "hello, world");
+    }
+
+    #[test]
+    fn test_ignore_case() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let s = Str::from("Hello");
+        assert!(s.eq_ignore_ascii_case("hello"));
+        assert!(s.eq_ignore_ascii_case("HELLO"));
+        assert!(!s.eq_ignore_ascii_case("hell"));
+
+        let cmd = Str::from("SetVolume 10");
+        assert!(cmd.starts_with_ignore_case("setvolume"));
+        assert!(!cmd.starts_with_ignore_case("volume"));
+        assert!(!Str::from("Se").starts_with_ignore_case("set"));
+        assert!(cmd.contains_ignore_case("VOLUME"));
+        assert!(cmd.contains_ignore_case(""));
+        assert!(!cmd.contains_ignore_case("mute"));
+    }
+
+    #[test]
+    fn test_from_arr_joined() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let parts: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        let arr = Arr::<Str>::from(&parts[..]);
+        let joined = Str::from_arr_joined(&arr, ", ");
+        assert_eq!(joined.as_str(), parts.join(", "));
+
+        assert!(Str::from_arr_joined(&Arr::new(), ", ").is_empty());
+        let single = Arr::<Str>::from(&["only".to_string()][..]);
+        assert_eq!(Str::from_arr_joined(&single, ", ").as_str(), "only");
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut s = Str::from("héllo");
+        s.insert(0, '¡');
+        s.insert_str(s.len(), " wörld");
+        assert_eq!(s.as_str(), "¡héllo wörld");
+
+        // 'é' starts at byte 3, after the 2-byte '¡' and 'h'
+        assert_eq!(s.remove(3), 'é');
+        s.insert(3, 'e');
+        assert_eq!(s.as_str(), "¡hello wörld");
+        assert_eq!(s.remove(0), '¡');
+        assert_eq!(s.as_str(), "hello wörld");
+    }
+
+    #[test]
+    #[should_panic(expected = "not a char boundary")]
+    fn test_insert_inside_char() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut s = Str::from("héllo");
+        // Byte 2 is the second byte of 'é'
+        s.insert(2, 'x');
+    }
+
+    #[test]
+    fn test_drop_empty_skips_destroy() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let before = mock::destroy_calls();
+        drop(Str::new());
+        drop(Str::default());
+        drop(Str::from(""));
+        assert_eq!(mock::destroy_calls(), before);
+
+        drop(Str::from("x"));
+        assert_eq!(mock::destroy_calls(), before + 1);
+    }
+
+    #[test]
+    fn test_capacity_without_host_support() {
+        let _guard = mock::lock_globals();
+        mock::install();
+        init_get_string_capacity(0);
+        init_reserve_string(0);
+
+        let mut s = Str::with_capacity(100);
+        assert!(s.is_empty());
+        assert_eq!(s.capacity(), 0);
+
+        s.push_str("hello");
+        s.reserve(200);
+        assert_eq!(s, "hello");
+        assert_eq!(s.capacity(), s.len());
+    }
+}
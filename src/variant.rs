@@ -1,5 +1,5 @@
 use std::mem::ManuallyDrop;
-use crate::{import_symbol, Str, Arr, Vec2, Vec3, Vec4, Mat4x4};
+use crate::{import_symbol, Str, Arr, ArrOps, CEnumRepr, Vec2, Vec3, Vec4, Mat4x4};
 
 import_symbol!(destroy_variant, DESTROY_VARIANT, init_destroy_variant, (variant: *mut Var) -> ());
 
@@ -72,6 +72,131 @@ pub enum Type {
     //Matrix4x3,
 }
 
+impl Type {
+    /// Check if this is one of the `Array*` types
+    #[must_use]
+    pub const fn is_array(self) -> bool {
+        matches!(self,
+            Type::ArrayBool | Type::ArrayChar8 | Type::ArrayChar16
+            | Type::ArrayInt8 | Type::ArrayInt16 | Type::ArrayInt32 | Type::ArrayInt64
+            | Type::ArrayUInt8 | Type::ArrayUInt16 | Type::ArrayUInt32 | Type::ArrayUInt64
+            | Type::ArrayPointer | Type::ArrayFloat | Type::ArrayDouble | Type::ArrayString
+            | Type::ArrayAny | Type::ArrayVector2 | Type::ArrayVector3 | Type::ArrayVector4
+            | Type::ArrayMatrix4x4)
+    }
+
+    /// Check if this holds a single fixed-size value
+    ///
+    /// This covers bools, characters, integers, pointers, floats and the
+    /// `Vector2`/`Vector3`/`Vector4` types, matching `ScalarValue`.
+    #[must_use]
+    pub const fn is_scalar(self) -> bool {
+        matches!(self,
+            Type::Bool | Type::Char8 | Type::Char16
+            | Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64
+            | Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64
+            | Type::Pointer | Type::Float | Type::Double
+            | Type::Vector2 | Type::Vector3 | Type::Vector4)
+    }
+
+    /// Check if this is an integer or floating-point type
+    ///
+    /// Characters and pointers are not considered numeric.
+    #[must_use]
+    pub const fn is_numeric(self) -> bool {
+        matches!(self,
+            Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64
+            | Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64
+            | Type::Float | Type::Double)
+    }
+
+    /// Check if this is the `String` type
+    #[must_use]
+    pub const fn is_string(self) -> bool {
+        matches!(self, Type::String)
+    }
+
+    /// Get the raw discriminant sent over the wire
+    #[must_use]
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Every `Type`, indexed by discriminant
+const TYPES: [Type; 43] = [
+    Type::Invalid, Type::Void, Type::Bool, Type::Char8, Type::Char16, Type::Int8, Type::Int16,
+    Type::Int32, Type::Int64, Type::UInt8, Type::UInt16, Type::UInt32, Type::UInt64,
+    Type::Pointer, Type::Float, Type::Double, Type::Function, Type::String, Type::Any,
+    Type::ArrayBool, Type::ArrayChar8, Type::ArrayChar16, Type::ArrayInt8, Type::ArrayInt16,
+    Type::ArrayInt32, Type::ArrayInt64, Type::ArrayUInt8, Type::ArrayUInt16, Type::ArrayUInt32,
+    Type::ArrayUInt64, Type::ArrayPointer, Type::ArrayFloat, Type::ArrayDouble,
+    Type::ArrayString, Type::ArrayAny, Type::ArrayVector2, Type::ArrayVector3,
+    Type::ArrayVector4, Type::ArrayMatrix4x4, Type::Vector2, Type::Vector3, Type::Vector4,
+    Type::Matrix4x4,
+];
+const _: () = {
+    let mut i = 0;
+    while i < TYPES.len() {
+        assert!(TYPES[i] as usize == i);
+        i += 1;
+    }
+};
+
+/// The last `Type` variant
+///
+/// The match is exhaustive, so adding a variant fails to compile until it is
+/// listed here and returned if it is the new last one. The count check below
+/// then requires it in `TYPES`, which `TryFrom` and the tests iterate.
+const LAST_TYPE: Type = {
+    const fn last(ty: Type) -> Type {
+        match ty {
+            Type::Invalid | Type::Void | Type::Bool | Type::Char8 | Type::Char16
+            | Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64
+            | Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64
+            | Type::Pointer | Type::Float | Type::Double | Type::Function
+            | Type::String | Type::Any
+            | Type::ArrayBool | Type::ArrayChar8 | Type::ArrayChar16
+            | Type::ArrayInt8 | Type::ArrayInt16 | Type::ArrayInt32 | Type::ArrayInt64
+            | Type::ArrayUInt8 | Type::ArrayUInt16 | Type::ArrayUInt32 | Type::ArrayUInt64
+            | Type::ArrayPointer | Type::ArrayFloat | Type::ArrayDouble | Type::ArrayString
+            | Type::ArrayAny | Type::ArrayVector2 | Type::ArrayVector3 | Type::ArrayVector4
+            | Type::ArrayMatrix4x4 | Type::Vector2 | Type::Vector3 | Type::Vector4
+            | Type::Matrix4x4 => Type::Matrix4x4,
+        }
+    }
+    last(Type::Invalid)
+};
+const _: () = assert!(TYPES.len() == LAST_TYPE as usize + 1);
+
+/// Error returned when an integer is not the discriminant of any `Type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidType(pub usize);
+
+impl std::fmt::Display for InvalidType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid type discriminant {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidType {}
+
+impl TryFrom<usize> for Type {
+    type Error = InvalidType;
+
+    fn try_from(n: usize) -> Result<Self, Self::Error> {
+        TYPES.get(n).copied().ok_or(InvalidType(n))
+    }
+}
+
+impl TryFrom<u8> for Type {
+    type Error = InvalidType;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        Type::try_from(usize::from(n))
+    }
+}
+
 /// Union containing all possible variant data types
 ///
 /// # Safety
@@ -170,6 +295,10 @@ pub struct Var {
 }
 const _: () = assert!(size_of::<Var>() == 32);
 
+/// Byte offset of the type tag within `Var`
+pub(crate) const VAR_TYPE_OFFSET: usize = std::mem::offset_of!(Var, current);
+const _: () = assert!(VAR_TYPE_OFFSET == 24);
+
 impl std::fmt::Debug for Var {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Var")
@@ -181,46 +310,7 @@ impl std::fmt::Debug for Var {
 
 impl std::fmt::Display for Var {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.get() {
-            Any::Invalid => write!(f, "Invalid"),
-            Any::Bool(v) => write!(f, "{}", v),
-            Any::Char8(v) => write!(f, "{}", v),
-            Any::Char16(v) => write!(f, "{}", v),
-            Any::Int8(v) => write!(f, "{}", v),
-            Any::Int16(v) => write!(f, "{}", v),
-            Any::Int32(v) => write!(f, "{}", v),
-            Any::Int64(v) => write!(f, "{}", v),
-            Any::UInt8(v) => write!(f, "{}", v),
-            Any::UInt16(v) => write!(f, "{}", v),
-            Any::UInt32(v) => write!(f, "{}", v),
-            Any::UInt64(v) => write!(f, "{}", v),
-            Any::Pointer(v) => write!(f, "0x{:x}", v),
-            Any::Float(v) => write!(f, "{}", v),
-            Any::Double(v) => write!(f, "{}", v),
-            Any::String(v) => write!(f, "{}", v),
-            Any::ArrayBool(v) => write!(f, "{:?}", v),
-            Any::ArrayChar8(v) => write!(f, "{:?}", v),
-            Any::ArrayChar16(v) => write!(f, "{:?}", v),
-            Any::ArrayInt8(v) => write!(f, "{:?}", v),
-            Any::ArrayInt16(v) => write!(f, "{:?}", v),
-            Any::ArrayInt32(v) => write!(f, "{:?}", v),
-            Any::ArrayInt64(v) => write!(f, "{:?}", v),
-            Any::ArrayUInt8(v) => write!(f, "{:?}", v),
-            Any::ArrayUInt16(v) => write!(f, "{:?}", v),
-            Any::ArrayUInt32(v) => write!(f, "{:?}", v),
-            Any::ArrayUInt64(v) => write!(f, "{:?}", v),
-            Any::ArrayPointer(v) => write!(f, "{:?}", v),
-            Any::ArrayFloat(v) => write!(f, "{:?}", v),
-            Any::ArrayDouble(v) => write!(f, "{:?}", v),
-            Any::ArrayString(v) => write!(f, "{:?}", v),
-            Any::ArrayVector2(v) => write!(f, "{:?}", v),
-            Any::ArrayVector3(v) => write!(f, "{:?}", v),
-            Any::ArrayVector4(v) => write!(f, "{:?}", v),
-            Any::ArrayMatrix4x4(v) => write!(f, "{:?}", v),
-            Any::Vector2(v) => write!(f, "{:?}", v),
-            Any::Vector3(v) => write!(f, "{:?}", v),
-            Any::Vector4(v) => write!(f, "{:?}", v),
-        }
+        std::fmt::Display::fmt(&self.get(), f)
     }
 }
 
@@ -274,6 +364,190 @@ pub enum Any {
     Vector4(Vec4),
 }
 
+/// Write `items` as `[a, b, c]`, formatting each element with `item`
+fn fmt_list<T>(
+    f: &mut std::fmt::Formatter<'_>,
+    items: &[T],
+    item: impl Fn(&mut std::fmt::Formatter<'_>, &T) -> std::fmt::Result,
+) -> std::fmt::Result {
+    write!(f, "[")?;
+    for (i, v) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        item(f, v)?;
+    }
+    write!(f, "]")
+}
+
+impl std::fmt::Display for Any {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn display<T: std::fmt::Display>(f: &mut std::fmt::Formatter<'_>, v: &T) -> std::fmt::Result {
+            write!(f, "{}", v)
+        }
+
+        match self {
+            Any::Invalid => write!(f, "Invalid"),
+            Any::Bool(v) => write!(f, "{}", v),
+            Any::Char8(v) => write!(f, "{}", v),
+            Any::Char16(v) => write!(f, "{}", v),
+            Any::Int8(v) => write!(f, "{}", v),
+            Any::Int16(v) => write!(f, "{}", v),
+            Any::Int32(v) => write!(f, "{}", v),
+            Any::Int64(v) => write!(f, "{}", v),
+            Any::UInt8(v) => write!(f, "{}", v),
+            Any::UInt16(v) => write!(f, "{}", v),
+            Any::UInt32(v) => write!(f, "{}", v),
+            Any::UInt64(v) => write!(f, "{}", v),
+            Any::Pointer(v) => write!(f, "0x{:x}", v),
+            Any::Float(v) => write!(f, "{}", v),
+            Any::Double(v) => write!(f, "{}", v),
+            Any::String(v) => write!(f, "{}", v),
+            Any::ArrayBool(v) => fmt_list(f, v, display),
+            Any::ArrayChar8(v) => fmt_list(f, v, display),
+            Any::ArrayChar16(v) => fmt_list(f, v, display),
+            Any::ArrayInt8(v) => fmt_list(f, v, display),
+            Any::ArrayInt16(v) => fmt_list(f, v, display),
+            Any::ArrayInt32(v) => fmt_list(f, v, display),
+            Any::ArrayInt64(v) => fmt_list(f, v, display),
+            Any::ArrayUInt8(v) => fmt_list(f, v, display),
+            Any::ArrayUInt16(v) => fmt_list(f, v, display),
+            Any::ArrayUInt32(v) => fmt_list(f, v, display),
+            Any::ArrayUInt64(v) => fmt_list(f, v, display),
+            Any::ArrayPointer(v) => fmt_list(f, v, |f, p| write!(f, "0x{:x}", p)),
+            Any::ArrayFloat(v) => fmt_list(f, v, display),
+            Any::ArrayDouble(v) => fmt_list(f, v, display),
+            Any::ArrayString(v) => fmt_list(f, v, display),
+            Any::ArrayVector2(v) => fmt_list(f, v, display),
+            Any::ArrayVector3(v) => fmt_list(f, v, display),
+            Any::ArrayVector4(v) => fmt_list(f, v, display),
+            Any::ArrayMatrix4x4(v) => fmt_list(f, v, display),
+            Any::Vector2(v) => write!(f, "{}", v),
+            Any::Vector3(v) => write!(f, "{}", v),
+            Any::Vector4(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Any {
+    /// Get the `Type` this value would have once converted to a Var
+    #[must_use]
+    pub fn current(&self) -> Type {
+        match self {
+            Any::Invalid => Type::Invalid,
+            Any::Bool(_) => Type::Bool,
+            Any::Char8(_) => Type::Char8,
+            Any::Char16(_) => Type::Char16,
+            Any::Int8(_) => Type::Int8,
+            Any::Int16(_) => Type::Int16,
+            Any::Int32(_) => Type::Int32,
+            Any::Int64(_) => Type::Int64,
+            Any::UInt8(_) => Type::UInt8,
+            Any::UInt16(_) => Type::UInt16,
+            Any::UInt32(_) => Type::UInt32,
+            Any::UInt64(_) => Type::UInt64,
+            Any::Pointer(_) => Type::Pointer,
+            Any::Float(_) => Type::Float,
+            Any::Double(_) => Type::Double,
+            Any::String(_) => Type::String,
+            Any::ArrayBool(_) => Type::ArrayBool,
+            Any::ArrayChar8(_) => Type::ArrayChar8,
+            Any::ArrayChar16(_) => Type::ArrayChar16,
+            Any::ArrayInt8(_) => Type::ArrayInt8,
+            Any::ArrayInt16(_) => Type::ArrayInt16,
+            Any::ArrayInt32(_) => Type::ArrayInt32,
+            Any::ArrayInt64(_) => Type::ArrayInt64,
+            Any::ArrayUInt8(_) => Type::ArrayUInt8,
+            Any::ArrayUInt16(_) => Type::ArrayUInt16,
+            Any::ArrayUInt32(_) => Type::ArrayUInt32,
+            Any::ArrayUInt64(_) => Type::ArrayUInt64,
+            Any::ArrayPointer(_) => Type::ArrayPointer,
+            Any::ArrayFloat(_) => Type::ArrayFloat,
+            Any::ArrayDouble(_) => Type::ArrayDouble,
+            Any::ArrayString(_) => Type::ArrayString,
+            Any::ArrayVector2(_) => Type::ArrayVector2,
+            Any::ArrayVector3(_) => Type::ArrayVector3,
+            Any::ArrayVector4(_) => Type::ArrayVector4,
+            Any::ArrayMatrix4x4(_) => Type::ArrayMatrix4x4,
+            Any::Vector2(_) => Type::Vector2,
+            Any::Vector3(_) => Type::Vector3,
+            Any::Vector4(_) => Type::Vector4,
+        }
+    }
+
+    /// Get the zero value of a `Type`: `false`, `0`, an empty string or array, or a zero vector
+    ///
+    /// Types an `Any` cannot hold (`Invalid`, `Void`, `Function`, `Any`,
+    /// `ArrayAny` and `Matrix4x4`) give `Any::Invalid`.
+    #[must_use]
+    pub fn default_for(ty: Type) -> Any {
+        match ty {
+            Type::Bool => Any::Bool(false),
+            Type::Char8 => Any::Char8(0),
+            Type::Char16 => Any::Char16(0),
+            Type::Int8 => Any::Int8(0),
+            Type::Int16 => Any::Int16(0),
+            Type::Int32 => Any::Int32(0),
+            Type::Int64 => Any::Int64(0),
+            Type::UInt8 => Any::UInt8(0),
+            Type::UInt16 => Any::UInt16(0),
+            Type::UInt32 => Any::UInt32(0),
+            Type::UInt64 => Any::UInt64(0),
+            Type::Pointer => Any::Pointer(0),
+            Type::Float => Any::Float(0.0),
+            Type::Double => Any::Double(0.0),
+            Type::String => Any::String(String::new()),
+            Type::ArrayBool => Any::ArrayBool(Vec::new()),
+            Type::ArrayChar8 => Any::ArrayChar8(Vec::new()),
+            Type::ArrayChar16 => Any::ArrayChar16(Vec::new()),
+            Type::ArrayInt8 => Any::ArrayInt8(Vec::new()),
+            Type::ArrayInt16 => Any::ArrayInt16(Vec::new()),
+            Type::ArrayInt32 => Any::ArrayInt32(Vec::new()),
+            Type::ArrayInt64 => Any::ArrayInt64(Vec::new()),
+            Type::ArrayUInt8 => Any::ArrayUInt8(Vec::new()),
+            Type::ArrayUInt16 => Any::ArrayUInt16(Vec::new()),
+            Type::ArrayUInt32 => Any::ArrayUInt32(Vec::new()),
+            Type::ArrayUInt64 => Any::ArrayUInt64(Vec::new()),
+            Type::ArrayPointer => Any::ArrayPointer(Vec::new()),
+            Type::ArrayFloat => Any::ArrayFloat(Vec::new()),
+            Type::ArrayDouble => Any::ArrayDouble(Vec::new()),
+            Type::ArrayString => Any::ArrayString(Vec::new()),
+            Type::ArrayVector2 => Any::ArrayVector2(Vec::new()),
+            Type::ArrayVector3 => Any::ArrayVector3(Vec::new()),
+            Type::ArrayVector4 => Any::ArrayVector4(Vec::new()),
+            Type::ArrayMatrix4x4 => Any::ArrayMatrix4x4(Vec::new()),
+            Type::Vector2 => Any::Vector2(Vec2::zero()),
+            Type::Vector3 => Any::Vector3(Vec3::zero()),
+            Type::Vector4 => Any::Vector4(Vec4::zero()),
+            Type::Invalid | Type::Void | Type::Function | Type::Any | Type::ArrayAny | Type::Matrix4x4 => Any::Invalid,
+        }
+    }
+
+    /// Check if the value is an array; see `Type::is_array`
+    #[must_use]
+    pub fn is_array(&self) -> bool {
+        self.current().is_array()
+    }
+
+    /// Check if the value is a single fixed-size value; see `Type::is_scalar`
+    #[must_use]
+    pub fn is_scalar(&self) -> bool {
+        self.current().is_scalar()
+    }
+
+    /// Check if the value is an integer or float; see `Type::is_numeric`
+    #[must_use]
+    pub fn is_numeric(&self) -> bool {
+        self.current().is_numeric()
+    }
+
+    /// Check if the value is a string
+    #[must_use]
+    pub fn is_string(&self) -> bool {
+        matches!(self, Any::String(_))
+    }
+}
+
 // ============================================
 // Core implementation
 // ============================================
@@ -283,7 +557,8 @@ impl Var {
     ///
     /// # Panics
     ///
-    /// May panic if C++ allocation fails (e.g., for String or Vector types).
+    /// If C++ allocation fails (e.g., for String or Vector types), panics or
+    /// aborts according to the [`AllocFailurePolicy`](crate::AllocFailurePolicy).
     /// The panic is safe - no resources will leak.
     pub fn new(value: &Any) -> Self {
         let mut variant = Var {
@@ -304,8 +579,8 @@ impl Var {
     /// Callers must ensure the variant is in the Invalid state or has been
     /// properly destroyed before calling this.
     ///
-    /// The C++ implementation must not throw exceptions. If allocation fails,
-    /// the process will abort.
+    /// If the host fails to allocate, panics or aborts according to the
+    /// [`AllocFailurePolicy`](crate::AllocFailurePolicy).
     fn construct(&mut self, value: &Any) {
         /// Macro to assign scalar (Copy) types to the union
         macro_rules! assign_scalar {
@@ -378,17 +653,59 @@ impl Var {
     /// This is the safe way to change a variant's value - it properly destroys
     /// the old data before constructing the new data.
     ///
-    /// # Safety
+    /// If the variant already holds a string or array of the same type as
+    /// `value`, the new contents are assigned into the existing host buffer
+    /// instead, so repeated updates of similar size reuse its allocation.
     ///
-    /// The C++ implementation must not throw exceptions. If allocation fails,
-    /// the process will abort.
+    /// # Panics
+    ///
+    /// If the host fails to allocate, panics or aborts according to the
+    /// [`AllocFailurePolicy`](crate::AllocFailurePolicy).
     pub fn set(&mut self, value: &Any) {
+        if self.assign_in_place(value) {
+            return;
+        }
         // Destroy existing data BEFORE constructing new data
         // This prevents memory leaks if the variant already holds data
         self.destroy();
         self.construct(value);
     }
 
+    /// Assign `value` into the active string or array field if the types match
+    ///
+    /// Returns false, leaving the variant untouched, if `value` has a different
+    /// type or is not a string or array. `ArrayString` is not reused because its
+    /// elements would have to be converted to `Str` first anyway.
+    fn assign_in_place(&mut self, value: &Any) -> bool {
+        // SAFETY: each arm only touches the field matching `current`, and the
+        // discriminant is left unchanged since the type stays the same
+        unsafe {
+            match (self.current, value) {
+                (Type::String, Any::String(v)) => (*self.data.str).set(v),
+                (Type::ArrayBool, Any::ArrayBool(v)) => (*self.data.vec_bool).set(v),
+                (Type::ArrayChar8, Any::ArrayChar8(v)) => (*self.data.vec_c8).set(v),
+                (Type::ArrayChar16, Any::ArrayChar16(v)) => (*self.data.vec_c16).set(v),
+                (Type::ArrayInt8, Any::ArrayInt8(v)) => (*self.data.vec_i8).set(v),
+                (Type::ArrayInt16, Any::ArrayInt16(v)) => (*self.data.vec_i16).set(v),
+                (Type::ArrayInt32, Any::ArrayInt32(v)) => (*self.data.vec_i32).set(v),
+                (Type::ArrayInt64, Any::ArrayInt64(v)) => (*self.data.vec_i64).set(v),
+                (Type::ArrayUInt8, Any::ArrayUInt8(v)) => (*self.data.vec_u8).set(v),
+                (Type::ArrayUInt16, Any::ArrayUInt16(v)) => (*self.data.vec_u16).set(v),
+                (Type::ArrayUInt32, Any::ArrayUInt32(v)) => (*self.data.vec_u32).set(v),
+                (Type::ArrayUInt64, Any::ArrayUInt64(v)) => (*self.data.vec_u64).set(v),
+                (Type::ArrayPointer, Any::ArrayPointer(v)) => (*self.data.vec_usize).set(v),
+                (Type::ArrayFloat, Any::ArrayFloat(v)) => (*self.data.vec_f32).set(v),
+                (Type::ArrayDouble, Any::ArrayDouble(v)) => (*self.data.vec_f64).set(v),
+                (Type::ArrayVector2, Any::ArrayVector2(v)) => (*self.data.vec_vec2).set(v),
+                (Type::ArrayVector3, Any::ArrayVector3(v)) => (*self.data.vec_vec3).set(v),
+                (Type::ArrayVector4, Any::ArrayVector4(v)) => (*self.data.vec_vec4).set(v),
+                (Type::ArrayMatrix4x4, Any::ArrayMatrix4x4(v)) => (*self.data.vec_mat4x4).set(v),
+                _ => return false,
+            }
+        }
+        true
+    }
+
     /// Get the current value as a Any (allocates and copies)
     ///
     /// # Safety
@@ -454,6 +771,31 @@ impl Var {
         }
     }
 
+    /// Take the current value out, leaving the variant `Invalid`
+    ///
+    /// The host payload is released right away instead of when the variant is dropped.
+    #[must_use = "if the value is not needed, use set(&Any::Invalid) instead"]
+    pub fn take(&mut self) -> Any {
+        let value = self.get();
+        self.set(&Any::Invalid);
+        value
+    }
+
+    /// Borrow the held array mutably, or None if the variant doesn't hold an `Arr<T>`
+    ///
+    /// Char8 and Char16 arrays can be borrowed as `Arr<i8>` and `Arr<u16>`.
+    /// The array stays owned by the variant, so edits take effect without
+    /// copying it out and back in.
+    #[must_use]
+    pub fn array_mut<T: ArrayValue>(&mut self) -> Option<&mut Arr<T>> {
+        T::array_mut(self)
+    }
+
+    /// Run `f` on the held array in place, or return None if the variant doesn't hold an `Arr<T>`
+    pub fn with_array_mut<T: ArrayValue, R>(&mut self, f: impl FnOnce(&mut Arr<T>) -> R) -> Option<R> {
+        self.array_mut().map(f)
+    }
+
     /// Get the current type of the variant
     #[must_use]
     pub fn current(&self) -> Type {
@@ -490,6 +832,75 @@ impl Default for Var {
     }
 }
 
+// ============================================
+// Borrowed variants
+// ============================================
+
+/// Borrowed, read-only view of a `Var` owned by the host
+///
+/// Hosts pass method arguments as `*const Var` that they still own. Wrapping such
+/// a pointer in `Var` would call `destroy_variant` on drop and free the host's data;
+/// `VarRef` has no `Drop`, so the host remains responsible for cleanup.
+///
+/// Dereferences to `Var`, so all read-only accessors are available.
+#[derive(Clone, Copy)]
+pub struct VarRef<'a> {
+    var: &'a Var,
+}
+
+impl<'a> VarRef<'a> {
+    /// Create a borrowed view from a raw host pointer
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be non-null, aligned, and point to a valid, initialized `Var`
+    /// - The variant must stay alive and unmodified for the lifetime `'a`
+    #[must_use]
+    pub unsafe fn from_raw(ptr: *const Var) -> Self {
+        // SAFETY: Caller guarantees the pointer is valid for 'a
+        Self { var: unsafe { &*ptr } }
+    }
+
+    /// Get the current value as an Any (allocates and copies)
+    #[must_use = "this allocates and copies data into a new Any"]
+    pub fn get(&self) -> Any {
+        self.var.get()
+    }
+
+    /// Get the current type of the variant
+    #[must_use]
+    pub fn current(&self) -> Type {
+        self.var.current()
+    }
+
+    /// Get the underlying variant for the full borrow lifetime
+    #[must_use]
+    pub fn as_var(&self) -> &'a Var {
+        self.var
+    }
+}
+
+impl std::ops::Deref for VarRef<'_> {
+    type Target = Var;
+    fn deref(&self) -> &Self::Target {
+        self.var
+    }
+}
+
+impl std::fmt::Debug for VarRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("VarRef")
+            .field(self.var)
+            .finish()
+    }
+}
+
+impl std::fmt::Display for VarRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.var, f)
+    }
+}
+
 // ============================================
 // Convenient From implementations
 // ============================================
@@ -560,15 +971,1201 @@ variant_from_vec!(Vec3, ArrayVector3);
 variant_from_vec!(Vec4, ArrayVector4);
 variant_from_vec!(Mat4x4, ArrayMatrix4x4);
 
-// Var From Any
-impl From<&Any> for Var {
-    fn from(value: &Any) -> Self {
-        Var::new(value)
+// FFI arrays copy their elements out and release the host allocation
+macro_rules! variant_from_arr {
+    ($t:ty, $variant:ident) => {
+        impl From<Arr<$t>> for Any {
+            fn from(value: Arr<$t>) -> Self {
+                Any::$variant(value.to_vec())
+            }
+        }
+    };
+}
+
+variant_from_arr!(bool, ArrayBool);
+variant_from_arr!(i8, ArrayInt8);
+variant_from_arr!(i16, ArrayInt16);
+variant_from_arr!(i32, ArrayInt32);
+variant_from_arr!(i64, ArrayInt64);
+variant_from_arr!(u8, ArrayUInt8);
+variant_from_arr!(u16, ArrayUInt16);
+variant_from_arr!(u32, ArrayUInt32);
+variant_from_arr!(u64, ArrayUInt64);
+variant_from_arr!(usize, ArrayPointer);
+variant_from_arr!(f32, ArrayFloat);
+variant_from_arr!(f64, ArrayDouble);
+variant_from_arr!(Vec2, ArrayVector2);
+variant_from_arr!(Vec3, ArrayVector3);
+variant_from_arr!(Vec4, ArrayVector4);
+variant_from_arr!(Mat4x4, ArrayMatrix4x4);
+
+impl From<Arr<Str>> for Any {
+    fn from(value: Arr<Str>) -> Self {
+        Any::ArrayString(value.to_string())
     }
 }
 
-impl From<Any> for Var {
-    fn from(value: Any) -> Self {
-        Var::new(&value)
+// ============================================
+// Generic scalar values
+// ============================================
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A Rust type that maps to a single scalar `Any` variant
+///
+/// Implemented for `bool`, the integer types, `f32`, `f64` and `Vec2`/`Vec3`/`Vec4`,
+/// so bridges can be written once as `fn set<T: ScalarValue>(&mut self, value: T)`.
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait ScalarValue: sealed::Sealed + Sized {
+    /// Wrap the value in its `Any` variant
+    fn into_any(self) -> Any;
+
+    /// Extract the value if `any` holds this type's variant
+    fn from_any(any: Any) -> Option<Self>;
+}
+
+macro_rules! scalar_value {
+    ($t:ty, $($variant:ident)|+) => {
+        impl sealed::Sealed for $t {}
+
+        impl ScalarValue for $t {
+            fn into_any(self) -> Any {
+                self.into()
+            }
+
+            fn from_any(any: Any) -> Option<Self> {
+                match any {
+                    $(Any::$variant(v))|+ => Some(v),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+// Char8/Char16 share their storage type with Int8/UInt16 and convert back too
+scalar_value!(bool, Bool);
+scalar_value!(i8, Int8 | Char8);
+scalar_value!(i16, Int16);
+scalar_value!(i32, Int32);
+scalar_value!(i64, Int64);
+scalar_value!(u8, UInt8);
+scalar_value!(u16, UInt16 | Char16);
+scalar_value!(u32, UInt32);
+scalar_value!(u64, UInt64);
+scalar_value!(usize, Pointer);
+scalar_value!(f32, Float);
+scalar_value!(f64, Double);
+scalar_value!(Vec2, Vector2);
+scalar_value!(Vec3, Vector3);
+scalar_value!(Vec4, Vector4);
+
+/// A Rust type a variant can hold an array of, for `Var::array_mut`
+///
+/// Implemented for every element type of the `Array*` variants except `Any`.
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait ArrayValue: sealed::Sealed + ArrOps {
+    #[doc(hidden)]
+    fn array_mut(var: &mut Var) -> Option<&mut Arr<Self>>;
+}
+
+macro_rules! array_value {
+    ($t:ty, $($variant:ident => $field:ident)|+) => {
+        impl ArrayValue for $t {
+            fn array_mut(var: &mut Var) -> Option<&mut Arr<Self>> {
+                // SAFETY: the field is only borrowed when `current` says it is active,
+                // and the borrow keeps `var` from being reassigned meanwhile
+                unsafe {
+                    match var.current {
+                        $(Type::$variant => Some(&mut *var.data.$field),)+
+                        _ => None,
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl sealed::Sealed for Str {}
+impl sealed::Sealed for Mat4x4 {}
+
+array_value!(bool, ArrayBool => vec_bool);
+array_value!(i8, ArrayInt8 => vec_i8 | ArrayChar8 => vec_c8);
+array_value!(i16, ArrayInt16 => vec_i16);
+array_value!(i32, ArrayInt32 => vec_i32);
+array_value!(i64, ArrayInt64 => vec_i64);
+array_value!(u8, ArrayUInt8 => vec_u8);
+array_value!(u16, ArrayUInt16 => vec_u16 | ArrayChar16 => vec_c16);
+array_value!(u32, ArrayUInt32 => vec_u32);
+array_value!(u64, ArrayUInt64 => vec_u64);
+array_value!(usize, ArrayPointer => vec_usize);
+array_value!(f32, ArrayFloat => vec_f32);
+array_value!(f64, ArrayDouble => vec_f64);
+array_value!(Str, ArrayString => vec_str);
+array_value!(Vec2, ArrayVector2 => vec_vec2);
+array_value!(Vec3, ArrayVector3 => vec_vec3);
+array_value!(Vec4, ArrayVector4 => vec_vec4);
+array_value!(Mat4x4, ArrayMatrix4x4 => vec_mat4x4);
+
+impl Any {
+    /// Clamp a numeric scalar into `[min, max]`
+    ///
+    /// Only integer, Float and Double variants are clamped, and only when both
+    /// bounds hold the same variant as the value and `min <= max`. Anything else,
+    /// including NaN values or bounds, is returned unchanged.
+    #[must_use]
+    pub fn clamp_numeric(self, min: Any, max: Any) -> Any {
+        macro_rules! clamp {
+            ($($variant:ident),+) => {
+                match (self, min, max) {
+                    $((Any::$variant(v), Any::$variant(lo), Any::$variant(hi)) if lo <= hi => {
+                        Any::$variant(if v < lo { lo } else if v > hi { hi } else { v })
+                    })+
+                    (value, _, _) => value,
+                }
+            };
+        }
+
+        clamp!(Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64, Float, Double)
+    }
+}
+
+// ============================================
+// Numeric conversions
+// ============================================
+
+/// A Rust number type an `Any` can be converted into with `Any::to_number`
+///
+/// Implemented for the integer types, `f32` and `f64`.
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait Number: sealed::Sealed + Copy {
+    /// Convert an integer, or None if it is out of range
+    fn from_i128(value: i128) -> Option<Self>;
+
+    /// Convert a float, or None if it is out of range
+    fn from_f64(value: f64) -> Option<Self>;
+}
+
+macro_rules! number_int {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn from_i128(value: i128) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+
+                fn from_f64(value: f64) -> Option<Self> {
+                    // i128::MAX as f64 rounds up to 2^127, so `<` keeps the cast exact
+                    let value = value.trunc();
+                    if value.is_finite() && value >= i128::MIN as f64 && value < i128::MAX as f64 {
+                        Self::from_i128(value as i128)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+number_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl Number for f32 {
+    fn from_i128(value: i128) -> Option<Self> {
+        Some(value as f32)
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        if value.is_finite() && value.abs() > f32::MAX as f64 {
+            None
+        } else {
+            Some(value as f32)
+        }
+    }
+}
+
+impl Number for f64 {
+    fn from_i128(value: i128) -> Option<Self> {
+        Some(value as f64)
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        Some(value)
+    }
+}
+
+impl Any {
+    /// Convert an integer, Float or Double variant into the number type `N`
+    ///
+    /// Integers convert exactly or not at all: None is returned if the value
+    /// doesn't fit in `N`. Floats converted to an integer type are truncated
+    /// toward zero and must be finite and in range. Converting to a float type
+    /// may round. Non-numeric variants return None.
+    ///
+    /// ```
+    /// use plugify::Any;
+    ///
+    /// assert_eq!(Any::Int32(7).to_number::<f32>(), Some(7.0));
+    /// assert_eq!(Any::Int64(300).to_number::<u8>(), None);
+    /// ```
+    #[must_use]
+    pub fn to_number<N: Number>(&self) -> Option<N> {
+        match *self {
+            Any::Int8(v) => N::from_i128(v.into()),
+            Any::Int16(v) => N::from_i128(v.into()),
+            Any::Int32(v) => N::from_i128(v.into()),
+            Any::Int64(v) => N::from_i128(v.into()),
+            Any::UInt8(v) => N::from_i128(v.into()),
+            Any::UInt16(v) => N::from_i128(v.into()),
+            Any::UInt32(v) => N::from_i128(v.into()),
+            Any::UInt64(v) => N::from_i128(v.into()),
+            Any::Float(v) => N::from_f64(v.into()),
+            Any::Double(v) => N::from_f64(v),
+            _ => None,
+        }
+    }
+}
+
+// ============================================
+// C-compatible enum conversions
+// ============================================
+
+impl Any {
+    /// Convert a C-compatible enum into the integer Any variant of its repr type
+    ///
+    /// E.g. a `#[repr(i32)]` enum becomes `Any::Int32`.
+    #[must_use]
+    pub fn from_enum<E: CEnumRepr>(value: E) -> Any
+    where
+        E::ReprInt: Into<Any>,
+    {
+        // SAFETY: CEnumRepr guarantees E and ReprInt have identical layout
+        let repr: E::ReprInt = unsafe { std::mem::transmute_copy(&value) };
+        repr.into()
     }
-}
\ No newline at end of file
+
+    /// Convert an integer Any variant back into a C-compatible enum
+    ///
+    /// Accepts any integer variant (including `Char8`/`Char16`) as long as the
+    /// value fits in the enum's repr type. The enum's `TryFrom<ReprInt>` impl
+    /// decides which discriminants are valid. Returns None for non-integer
+    /// variants, out-of-range values or unknown discriminants.
+    #[must_use]
+    pub fn to_enum<E: CEnumRepr + TryFrom<E::ReprInt>>(&self) -> Option<E>
+    where
+        E::ReprInt: TryFrom<i128>,
+    {
+        let value = match *self {
+            Any::Char8(v) | Any::Int8(v) => v as i128,
+            Any::Char16(v) | Any::UInt16(v) => v as i128,
+            Any::Int16(v) => v as i128,
+            Any::Int32(v) => v as i128,
+            Any::Int64(v) => v as i128,
+            Any::UInt8(v) => v as i128,
+            Any::UInt32(v) => v as i128,
+            Any::UInt64(v) => v as i128,
+            _ => return None,
+        };
+        let repr = E::ReprInt::try_from(value).ok()?;
+        E::try_from(repr).ok()
+    }
+}
+
+// ============================================
+// Visitor
+// ============================================
+
+/// Callbacks for each `Any` variant, driven by [`Any::visit`]
+///
+/// Every method has an empty default, so implementors only override the
+/// variants they care about.
+pub trait AnyVisitor {
+    fn visit_invalid(&mut self) {}
+    fn visit_bool(&mut self, _value: bool) {}
+    fn visit_char8(&mut self, _value: i8) {}
+    fn visit_char16(&mut self, _value: u16) {}
+    fn visit_int8(&mut self, _value: i8) {}
+    fn visit_int16(&mut self, _value: i16) {}
+    fn visit_int32(&mut self, _value: i32) {}
+    fn visit_int64(&mut self, _value: i64) {}
+    fn visit_uint8(&mut self, _value: u8) {}
+    fn visit_uint16(&mut self, _value: u16) {}
+    fn visit_uint32(&mut self, _value: u32) {}
+    fn visit_uint64(&mut self, _value: u64) {}
+    fn visit_pointer(&mut self, _value: usize) {}
+    fn visit_float(&mut self, _value: f32) {}
+    fn visit_double(&mut self, _value: f64) {}
+    fn visit_string(&mut self, _value: &str) {}
+    fn visit_array_bool(&mut self, _value: &[bool]) {}
+    fn visit_array_char8(&mut self, _value: &[i8]) {}
+    fn visit_array_char16(&mut self, _value: &[u16]) {}
+    fn visit_array_int8(&mut self, _value: &[i8]) {}
+    fn visit_array_int16(&mut self, _value: &[i16]) {}
+    fn visit_array_int32(&mut self, _value: &[i32]) {}
+    fn visit_array_int64(&mut self, _value: &[i64]) {}
+    fn visit_array_uint8(&mut self, _value: &[u8]) {}
+    fn visit_array_uint16(&mut self, _value: &[u16]) {}
+    fn visit_array_uint32(&mut self, _value: &[u32]) {}
+    fn visit_array_uint64(&mut self, _value: &[u64]) {}
+    fn visit_array_pointer(&mut self, _value: &[usize]) {}
+    fn visit_array_float(&mut self, _value: &[f32]) {}
+    fn visit_array_double(&mut self, _value: &[f64]) {}
+    fn visit_array_string(&mut self, _value: &[String]) {}
+    fn visit_array_vector2(&mut self, _value: &[Vec2]) {}
+    fn visit_array_vector3(&mut self, _value: &[Vec3]) {}
+    fn visit_array_vector4(&mut self, _value: &[Vec4]) {}
+    fn visit_array_matrix4x4(&mut self, _value: &[Mat4x4]) {}
+    fn visit_vector2(&mut self, _value: Vec2) {}
+    fn visit_vector3(&mut self, _value: Vec3) {}
+    fn visit_vector4(&mut self, _value: Vec4) {}
+}
+
+impl Any {
+    /// Dispatch the held value to the matching `AnyVisitor` method
+    pub fn visit<V: AnyVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Any::Invalid => visitor.visit_invalid(),
+            Any::Bool(v) => visitor.visit_bool(*v),
+            Any::Char8(v) => visitor.visit_char8(*v),
+            Any::Char16(v) => visitor.visit_char16(*v),
+            Any::Int8(v) => visitor.visit_int8(*v),
+            Any::Int16(v) => visitor.visit_int16(*v),
+            Any::Int32(v) => visitor.visit_int32(*v),
+            Any::Int64(v) => visitor.visit_int64(*v),
+            Any::UInt8(v) => visitor.visit_uint8(*v),
+            Any::UInt16(v) => visitor.visit_uint16(*v),
+            Any::UInt32(v) => visitor.visit_uint32(*v),
+            Any::UInt64(v) => visitor.visit_uint64(*v),
+            Any::Pointer(v) => visitor.visit_pointer(*v),
+            Any::Float(v) => visitor.visit_float(*v),
+            Any::Double(v) => visitor.visit_double(*v),
+            Any::String(v) => visitor.visit_string(v),
+            Any::ArrayBool(v) => visitor.visit_array_bool(v),
+            Any::ArrayChar8(v) => visitor.visit_array_char8(v),
+            Any::ArrayChar16(v) => visitor.visit_array_char16(v),
+            Any::ArrayInt8(v) => visitor.visit_array_int8(v),
+            Any::ArrayInt16(v) => visitor.visit_array_int16(v),
+            Any::ArrayInt32(v) => visitor.visit_array_int32(v),
+            Any::ArrayInt64(v) => visitor.visit_array_int64(v),
+            Any::ArrayUInt8(v) => visitor.visit_array_uint8(v),
+            Any::ArrayUInt16(v) => visitor.visit_array_uint16(v),
+            Any::ArrayUInt32(v) => visitor.visit_array_uint32(v),
+            Any::ArrayUInt64(v) => visitor.visit_array_uint64(v),
+            Any::ArrayPointer(v) => visitor.visit_array_pointer(v),
+            Any::ArrayFloat(v) => visitor.visit_array_float(v),
+            Any::ArrayDouble(v) => visitor.visit_array_double(v),
+            Any::ArrayString(v) => visitor.visit_array_string(v),
+            Any::ArrayVector2(v) => visitor.visit_array_vector2(v),
+            Any::ArrayVector3(v) => visitor.visit_array_vector3(v),
+            Any::ArrayVector4(v) => visitor.visit_array_vector4(v),
+            Any::ArrayMatrix4x4(v) => visitor.visit_array_matrix4x4(v),
+            Any::Vector2(v) => visitor.visit_vector2(*v),
+            Any::Vector3(v) => visitor.visit_vector3(*v),
+            Any::Vector4(v) => visitor.visit_vector4(*v),
+        }
+    }
+}
+
+// ============================================
+// Equality and hashing
+// ============================================
+
+/// Append a canonical little-endian encoding of a payload value
+///
+/// Floats are encoded by bit pattern, so `NaN` equals itself and `0.0` differs from `-0.0`.
+/// Pointers and lengths are widened to `u64` so the bytes are the same on every target.
+trait Canonical {
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+macro_rules! canonical_int {
+    ($($t:ty),*) => {
+        $(impl Canonical for $t {
+            fn encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+canonical_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl Canonical for usize {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as u64).encode(out);
+    }
+}
+
+impl Canonical for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+}
+
+impl Canonical for f32 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode(out);
+    }
+}
+
+impl Canonical for f64 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode(out);
+    }
+}
+
+impl Canonical for &str {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Canonical for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_str().encode(out);
+    }
+}
+
+impl Canonical for Vec2 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        [self.x, self.y].iter().for_each(|v| v.encode(out));
+    }
+}
+
+impl Canonical for Vec3 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        [self.x, self.y, self.z].iter().for_each(|v| v.encode(out));
+    }
+}
+
+impl Canonical for Vec4 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        [self.x, self.y, self.z, self.w].iter().for_each(|v| v.encode(out));
+    }
+}
+
+impl Canonical for Mat4x4 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.iter_elements().for_each(|v| v.encode(out));
+    }
+}
+
+/// Visitor collecting the canonical encoding of an Any
+struct CanonicalBytes(Vec<u8>);
+
+macro_rules! canonical_visit {
+    ($($method:ident: $t:ty),* $(,)?) => {
+        $(fn $method(&mut self, value: $t) {
+            value.encode(&mut self.0);
+        })*
+    };
+}
+
+macro_rules! canonical_visit_slice {
+    ($($method:ident: $t:ty),* $(,)?) => {
+        $(fn $method(&mut self, value: &[$t]) {
+            value.len().encode(&mut self.0);
+            value.iter().for_each(|v| v.encode(&mut self.0));
+        })*
+    };
+}
+
+impl AnyVisitor for CanonicalBytes {
+    canonical_visit!(
+        visit_bool: bool,
+        visit_char8: i8,
+        visit_char16: u16,
+        visit_int8: i8,
+        visit_int16: i16,
+        visit_int32: i32,
+        visit_int64: i64,
+        visit_uint8: u8,
+        visit_uint16: u16,
+        visit_uint32: u32,
+        visit_uint64: u64,
+        visit_pointer: usize,
+        visit_float: f32,
+        visit_double: f64,
+        visit_string: &str,
+        visit_vector2: Vec2,
+        visit_vector3: Vec3,
+        visit_vector4: Vec4,
+    );
+
+    canonical_visit_slice!(
+        visit_array_bool: bool,
+        visit_array_char8: i8,
+        visit_array_char16: u16,
+        visit_array_int8: i8,
+        visit_array_int16: i16,
+        visit_array_int32: i32,
+        visit_array_int64: i64,
+        visit_array_uint8: u8,
+        visit_array_uint16: u16,
+        visit_array_uint32: u32,
+        visit_array_uint64: u64,
+        visit_array_pointer: usize,
+        visit_array_float: f32,
+        visit_array_double: f64,
+        visit_array_string: String,
+        visit_array_vector2: Vec2,
+        visit_array_vector3: Vec3,
+        visit_array_vector4: Vec4,
+        visit_array_matrix4x4: Mat4x4,
+    );
+}
+
+impl Var {
+    /// Bit pattern of an inline payload, or None for strings and arrays
+    ///
+    /// Floats contribute their bits, packed 32 at a time for the vector types.
+    fn inline_bits(&self) -> Option<u128> {
+        fn pack(components: &[f32]) -> u128 {
+            components.iter().fold(0, |bits, c| bits << 32 | c.to_bits() as u128)
+        }
+        // SAFETY: each arm reads the field that `current` names as active
+        unsafe {
+            Some(match self.current {
+                Type::Invalid | Type::Void => 0,
+                Type::Bool => self.data.boolean as u128,
+                Type::Char8 => self.data.char8 as u8 as u128,
+                Type::Char16 => self.data.char16 as u128,
+                Type::Int8 => self.data.int8 as u8 as u128,
+                Type::Int16 => self.data.int16 as u16 as u128,
+                Type::Int32 => self.data.int32 as u32 as u128,
+                Type::Int64 => self.data.int64 as u64 as u128,
+                Type::UInt8 => self.data.uint8 as u128,
+                Type::UInt16 => self.data.uint16 as u128,
+                Type::UInt32 => self.data.uint32 as u128,
+                Type::UInt64 => self.data.uint64 as u128,
+                Type::Pointer => self.data.ptr as u128,
+                Type::Float => self.data.flt.to_bits() as u128,
+                Type::Double => self.data.dbl.to_bits() as u128,
+                Type::Vector2 => pack(&[self.data.vec2.x, self.data.vec2.y]),
+                Type::Vector3 => pack(&[self.data.vec3.x, self.data.vec3.y, self.data.vec3.z]),
+                Type::Vector4 => pack(&[self.data.vec4.x, self.data.vec4.y, self.data.vec4.z, self.data.vec4.w]),
+                _ => return None,
+            })
+        }
+    }
+
+    /// Type tag followed by the canonical payload encoding
+    fn canonical_bytes(&self) -> Vec<u8> {
+        self.get().encode()
+    }
+}
+
+/// Variants are equal if they hold the same type and the same value
+///
+/// Floating-point payloads are compared by bit pattern so that equality is
+/// reflexive and consistent with `Hash`: `NaN == NaN`, but `0.0 != -0.0`.
+/// Inline values are compared in place; only strings and arrays are copied
+/// out of the host buffers.
+impl PartialEq for Var {
+    fn eq(&self, other: &Self) -> bool {
+        if self.current != other.current {
+            return false;
+        }
+        match (self.inline_bits(), other.inline_bits()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.canonical_bytes() == other.canonical_bytes(),
+        }
+    }
+}
+
+impl Eq for Var {}
+
+/// Hashes the type and value, with floats hashed by bit pattern (see `PartialEq`)
+impl std::hash::Hash for Var {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self.inline_bits() {
+            Some(bits) => {
+                (self.current as u8).hash(state);
+                bits.hash(state);
+            }
+            None => self.canonical_bytes().hash(state),
+        }
+    }
+}
+
+// ============================================
+// Binary encoding
+// ============================================
+
+/// Error returned by `Any::decode`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended in the middle of a value
+    UnexpectedEnd,
+    /// The tag byte does not name a type an `Any` can hold
+    InvalidTag(u8),
+    /// A bool byte other than 0/1, or a pointer too wide for this target
+    InvalidValue,
+    /// A string payload is not valid UTF-8
+    InvalidUtf8,
+    /// Bytes remain after the encoded value
+    TrailingBytes(usize),
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            DecodeError::InvalidTag(tag) => write!(f, "invalid type tag {}", tag),
+            DecodeError::InvalidValue => write!(f, "invalid value"),
+            DecodeError::InvalidUtf8 => write!(f, "string is not valid UTF-8"),
+            DecodeError::TrailingBytes(n) => write!(f, "{} trailing bytes after value", n),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Split `n` bytes off the front of `input`
+fn take<'a>(input: &mut &'a [u8], n: usize) -> Result<&'a [u8], DecodeError> {
+    let (head, tail) = input.split_at_checked(n).ok_or(DecodeError::UnexpectedEnd)?;
+    *input = tail;
+    Ok(head)
+}
+
+/// Inverse of `Canonical`, reading a payload value from the front of the input
+trait CanonicalDecode: Sized {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError>;
+}
+
+macro_rules! canonical_decode_int {
+    ($($t:ty),*) => {
+        $(impl CanonicalDecode for $t {
+            fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                let bytes = take(input, size_of::<$t>())?;
+                Ok(<$t>::from_le_bytes(bytes.try_into().unwrap()))
+            }
+        })*
+    };
+}
+
+canonical_decode_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl CanonicalDecode for usize {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        usize::try_from(u64::decode(input)?).map_err(|_| DecodeError::InvalidValue)
+    }
+}
+
+impl CanonicalDecode for bool {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl CanonicalDecode for f32 {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(f32::from_bits(u32::decode(input)?))
+    }
+}
+
+impl CanonicalDecode for f64 {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(f64::from_bits(u64::decode(input)?))
+    }
+}
+
+impl CanonicalDecode for String {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let len = usize::decode(input)?;
+        let bytes = take(input, len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8)
+    }
+}
+
+impl CanonicalDecode for Vec2 {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(Vec2::new(f32::decode(input)?, f32::decode(input)?))
+    }
+}
+
+impl CanonicalDecode for Vec3 {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(Vec3::new(f32::decode(input)?, f32::decode(input)?, f32::decode(input)?))
+    }
+}
+
+impl CanonicalDecode for Vec4 {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(Vec4::new(f32::decode(input)?, f32::decode(input)?, f32::decode(input)?, f32::decode(input)?))
+    }
+}
+
+impl CanonicalDecode for Mat4x4 {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let mut m = [[0.0f32; 4]; 4];
+        for v in m.iter_mut().flatten() {
+            *v = f32::decode(input)?;
+        }
+        Ok(Mat4x4::new(m))
+    }
+}
+
+impl<T: CanonicalDecode> CanonicalDecode for Vec<T> {
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let len = usize::decode(input)?;
+        // Every element takes at least one byte, so a bogus length can't over-allocate
+        let mut items = Vec::with_capacity(len.min(input.len()));
+        for _ in 0..len {
+            items.push(T::decode(input)?);
+        }
+        Ok(items)
+    }
+}
+
+impl Any {
+    /// Encode the value as a self-describing byte string
+    ///
+    /// The format is a `Type` tag byte followed by the payload: little-endian
+    /// scalars (floats by bit pattern), `u64` lengths before strings and arrays,
+    /// and vector/matrix components in order. Use `Any::decode` to read it back.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = CanonicalBytes(vec![self.current() as u8]);
+        self.visit(&mut bytes);
+        bytes.0
+    }
+
+    /// Decode a value produced by `Any::encode`
+    ///
+    /// The whole input must be consumed by a single value.
+    pub fn decode(bytes: &[u8]) -> Result<Any, DecodeError> {
+        let mut input = bytes;
+        let tag = u8::decode(&mut input)?;
+
+        macro_rules! decode_variant {
+            ($($variant:ident),* $(,)?) => {
+                match tag {
+                    t if t == Type::Invalid as u8 => Any::Invalid,
+                    $(t if t == Type::$variant as u8 => Any::$variant(CanonicalDecode::decode(&mut input)?),)*
+                    t => return Err(DecodeError::InvalidTag(t)),
+                }
+            };
+        }
+
+        let value = decode_variant!(
+            Bool, Char8, Char16, Int8, Int16, Int32, Int64,
+            UInt8, UInt16, UInt32, UInt64, Pointer, Float, Double, String,
+            ArrayBool, ArrayChar8, ArrayChar16, ArrayInt8, ArrayInt16, ArrayInt32, ArrayInt64,
+            ArrayUInt8, ArrayUInt16, ArrayUInt32, ArrayUInt64, ArrayPointer, ArrayFloat, ArrayDouble,
+            ArrayString, ArrayVector2, ArrayVector3, ArrayVector4, ArrayMatrix4x4,
+            Vector2, Vector3, Vector4,
+        );
+
+        if !input.is_empty() {
+            return Err(DecodeError::TrailingBytes(input.len()));
+        }
+        Ok(value)
+    }
+}
+
+// Var From Any
+impl From<&Any> for Var {
+    fn from(value: &Any) -> Self {
+        Var::new(value)
+    }
+}
+
+impl From<Any> for Var {
+    fn from(value: Any) -> Self {
+        Var::new(&value)
+    }
+}
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(i32)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Status {
+        Idle = 0,
+        Running = 1,
+        Stopped = -1,
+    }
+
+    crate::vector_enum_traits!(Status, i32);
+
+    impl TryFrom<i32> for Status {
+        type Error = i32;
+
+        fn try_from(value: i32) -> Result<Self, i32> {
+            match value {
+                0 => Ok(Status::Idle),
+                1 => Ok(Status::Running),
+                -1 => Ok(Status::Stopped),
+                other => Err(other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_enum_round_trip() {
+        for status in [Status::Idle, Status::Running, Status::Stopped] {
+            let any = Any::from_enum(status);
+            assert!(matches!(any, Any::Int32(_)));
+            assert_eq!(any.to_enum::<Status>(), Some(status));
+        }
+
+        assert_eq!(Any::Int64(1).to_enum::<Status>(), Some(Status::Running));
+        assert_eq!(Any::Int64(i64::MAX).to_enum::<Status>(), None);
+        assert_eq!(Any::Int32(7).to_enum::<Status>(), None);
+        assert_eq!(Any::Float(1.0).to_enum::<Status>(), None);
+    }
+
+    #[test]
+    fn test_any_display() {
+        assert_eq!(Any::ArrayFloat(vec![1.0, 2.5, -3.0]).to_string(), "[1, 2.5, -3]");
+        assert_eq!(Any::ArrayFloat(vec![]).to_string(), "[]");
+        assert_eq!(Any::String("hello".to_string()).to_string(), "hello");
+        assert_eq!(Any::ArrayString(vec!["a".to_string(), "b".to_string()]).to_string(), "[a, b]");
+        assert_eq!(Any::ArrayPointer(vec![255]).to_string(), "[0xff]");
+    }
+
+    #[test]
+    fn test_var_ref_does_not_destroy() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+
+        let var = Var::new(&Any::from("hello"));
+        assert_eq!(crate::mock::live_strings(), 1);
+
+        {
+            let borrowed = unsafe { VarRef::from_raw(&var) };
+            assert_eq!(borrowed.current(), Type::String);
+            assert!(matches!(borrowed.get(), Any::String(s) if s == "hello"));
+            assert_eq!(borrowed.to_string(), "hello");
+        }
+
+        // The borrowed view must not have destroyed the owner's string
+        assert_eq!(crate::mock::live_strings(), 1);
+        assert!(matches!(var.get(), Any::String(s) if s == "hello"));
+
+        drop(var);
+        assert_eq!(crate::mock::live_strings(), 0);
+    }
+
+    #[test]
+    fn test_visitor_counts_array_elements() {
+        #[derive(Default)]
+        struct ElementCounter {
+            elements: usize,
+            scalars: usize,
+        }
+
+        impl AnyVisitor for ElementCounter {
+            fn visit_int32(&mut self, _value: i32) {
+                self.scalars += 1;
+            }
+            fn visit_array_int32(&mut self, value: &[i32]) {
+                self.elements += value.len();
+            }
+            fn visit_array_string(&mut self, value: &[String]) {
+                self.elements += value.len();
+            }
+            fn visit_array_vector3(&mut self, value: &[Vec3]) {
+                self.elements += value.len();
+            }
+        }
+
+        let values = [
+            Any::ArrayInt32(vec![1, 2, 3]),
+            Any::ArrayString(vec!["a".to_string(), "b".to_string()]),
+            Any::ArrayVector3(vec![Vec3::new(0.0, 0.0, 0.0)]),
+            Any::Int32(7),
+            Any::ArrayFloat(vec![1.0; 10]),
+            Any::Invalid,
+        ];
+
+        let mut counter = ElementCounter::default();
+        for value in &values {
+            value.visit(&mut counter);
+        }
+        assert_eq!(counter.elements, 6);
+        assert_eq!(counter.scalars, 1);
+    }
+
+    #[test]
+    fn test_any_from_arr() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+
+        let floats = Arr::from_slice(&[1.0f32, 2.5]);
+        assert_eq!(crate::mock::live_vectors(), 1);
+        let any = Any::from(floats);
+        assert!(matches!(&any, Any::ArrayFloat(v) if v == &[1.0, 2.5]));
+        assert_eq!(crate::mock::live_vectors(), 0);
+
+        let strings: Arr<Str> = Arr::from(vec!["a".to_string(), "b".to_string()]);
+        let any: Any = strings.into();
+        assert!(matches!(&any, Any::ArrayString(v) if v == &["a", "b"]));
+        assert_eq!(crate::mock::live_strings(), 0);
+    }
+
+    #[test]
+    fn test_var_eq_hash() {
+        use std::collections::HashMap;
+
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+
+        let a = Var::new(&Any::String("key".to_string()));
+        let b = Var::new(&Any::String("key".to_string()));
+        let c = Var::new(&Any::String("other".to_string()));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        // Same bits, different type
+        assert_ne!(Var::new(&Any::Int8(1)), Var::new(&Any::Char8(1)));
+
+        // Floats compare by bit pattern
+        let nan = Var::new(&Any::Double(f64::NAN));
+        assert_eq!(nan, nan.clone());
+        assert_ne!(Var::new(&Any::Float(0.0)), Var::new(&Any::Float(-0.0)));
+
+        let arr = Var::new(&Any::ArrayInt32(vec![1, 2, 3]));
+        assert_eq!(arr, Var::new(&Any::ArrayInt32(vec![1, 2, 3])));
+        assert_ne!(arr, Var::new(&Any::ArrayInt32(vec![1, 2])));
+
+        let mut cache = HashMap::new();
+        cache.insert(a, 1);
+        cache.insert(c, 2);
+        cache.insert(arr, 3);
+        assert_eq!(cache.get(&b), Some(&1));
+        assert_eq!(cache.get(&Var::new(&Any::ArrayInt32(vec![1, 2, 3]))), Some(&3));
+        assert_eq!(cache.get(&Var::new(&Any::Int32(1))), None);
+
+        let up = Var::new(&Any::Vector3(Vec3::up()));
+        assert_eq!(up, Var::new(&Any::Vector3(Vec3::up())));
+        assert_ne!(up, Var::new(&Any::Vector3(Vec3::right())));
+        assert_ne!(Var::new(&Any::Int32(-1)), Var::new(&Any::Int32(1)));
+        cache.insert(up, 4);
+        cache.insert(Var::new(&Any::Int64(-1)), 5);
+        assert_eq!(cache.get(&Var::new(&Any::Vector3(Vec3::up()))), Some(&4));
+        assert_eq!(cache.get(&Var::new(&Any::Int64(-1))), Some(&5));
+        assert_eq!(cache.get(&Var::new(&Any::UInt64(u64::MAX))), None);
+    }
+
+    #[test]
+    fn test_scalar_value_generic() {
+        fn round_trip<T: ScalarValue + Copy + PartialEq + std::fmt::Debug>(value: T) -> Option<T> {
+            let var = Var::new(&value.into_any());
+            T::from_any(var.get())
+        }
+
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+
+        assert_eq!(round_trip(42i32), Some(42));
+        assert_eq!(round_trip(Vec3::new(1.0, 2.0, 3.0)), Some(Vec3::new(1.0, 2.0, 3.0)));
+        assert_eq!(round_trip(true), Some(true));
+
+        assert_eq!(i32::from_any(Any::Float(1.0)), None);
+        assert_eq!(u16::from_any(Any::Char16(7)), Some(7));
+    }
+
+    #[test]
+    fn test_clamp_numeric() {
+        let clamped = Any::Float(7.5).clamp_numeric(Any::Float(0.0), Any::Float(1.0));
+        assert!(matches!(clamped, Any::Float(v) if v == 1.0));
+
+        let inside = Any::Float(0.25).clamp_numeric(Any::Float(0.0), Any::Float(1.0));
+        assert!(matches!(inside, Any::Float(v) if v == 0.25));
+
+        let low = Any::Int64(-9).clamp_numeric(Any::Int64(-3), Any::Int64(3));
+        assert!(matches!(low, Any::Int64(-3)));
+
+        // Mismatched bounds, inverted bounds and non-numeric values pass through
+        let mismatch = Any::Float(7.5).clamp_numeric(Any::Double(0.0), Any::Double(1.0));
+        assert!(matches!(mismatch, Any::Float(v) if v == 7.5));
+        let inverted = Any::Int32(5).clamp_numeric(Any::Int32(3), Any::Int32(1));
+        assert!(matches!(inverted, Any::Int32(5)));
+        let text = Any::String("x".into()).clamp_numeric(Any::Int32(0), Any::Int32(1));
+        assert!(matches!(text, Any::String(ref s) if s == "x"));
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let values = [
+            Any::Invalid,
+            Any::Bool(true),
+            Any::Char8(-5),
+            Any::Int32(-123456),
+            Any::UInt64(u64::MAX),
+            Any::Pointer(0xdead_beef),
+            Any::Double(f64::NAN),
+            Any::String("héllo".into()),
+            Any::ArrayString(vec!["a".into(), String::new(), "ccc".into()]),
+            Any::ArrayFloat(vec![1.0, -0.0, f32::INFINITY]),
+            Any::ArrayVector3(vec![Vec3::new(1.0, 2.0, 3.0)]),
+            Any::ArrayMatrix4x4(vec![Mat4x4::identity()]),
+            Any::Vector4(Vec4::new(1.0, 2.0, 3.0, 4.0)),
+        ];
+        for value in &values {
+            let bytes = value.encode();
+            let decoded = Any::decode(&bytes).unwrap();
+            assert_eq!(decoded.current(), value.current());
+            assert_eq!(decoded.encode(), bytes, "{:?}", value);
+        }
+
+        let bytes = Any::Int16(0x0102).encode();
+        assert_eq!(bytes, vec![Type::Int16 as u8, 0x02, 0x01]);
+        assert!(matches!(Any::decode(&Any::String("x".into()).encode()), Ok(Any::String(ref s)) if s == "x"));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let bytes = Any::ArrayInt32(vec![1, 2, 3]).encode();
+        assert_eq!(Any::decode(&bytes[..bytes.len() - 1]).err(), Some(DecodeError::UnexpectedEnd));
+        assert_eq!(Any::decode(&[]).err(), Some(DecodeError::UnexpectedEnd));
+
+        let mut long = bytes.clone();
+        long.push(0);
+        assert_eq!(Any::decode(&long).err(), Some(DecodeError::TrailingBytes(1)));
+
+        assert_eq!(Any::decode(&[Type::Void as u8]).err(), Some(DecodeError::InvalidTag(Type::Void as u8)));
+        assert_eq!(Any::decode(&[Type::Bool as u8, 2]).err(), Some(DecodeError::InvalidValue));
+
+        let mut bad_utf8 = Any::String("ab".into()).encode();
+        *bad_utf8.last_mut().unwrap() = 0xff;
+        assert_eq!(Any::decode(&bad_utf8).err(), Some(DecodeError::InvalidUtf8));
+    }
+
+    #[test]
+    fn test_classification() {
+        let array = Any::ArrayInt32(vec![1, 2]);
+        assert!(array.is_array() && !array.is_scalar() && !array.is_numeric());
+
+        let number = Any::Double(1.5);
+        assert!(number.is_scalar() && number.is_numeric() && !number.is_array());
+
+        let flag = Any::Bool(true);
+        assert!(flag.is_scalar() && !flag.is_numeric());
+
+        let text = Any::String("hi".into());
+        assert!(text.is_string() && !text.is_scalar() && !text.is_array());
+
+        let vector = Any::Vector3(Vec3::zero());
+        assert!(vector.is_scalar() && !vector.is_numeric());
+
+        assert!(!Any::Invalid.is_scalar() && !Any::Invalid.is_array());
+        assert!(Type::ArrayAny.is_array());
+        assert!(!Type::Char8.is_numeric() && Type::UInt64.is_numeric());
+        assert!(Type::String.is_string());
+    }
+
+    #[test]
+    fn test_set_reuses_array_buffer() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+
+        let mut var = Var::new(&Any::ArrayInt32(vec![1, 2, 3, 4]));
+        let before = unsafe { var.data.vec_i32.as_slice().as_ptr() };
+
+        var.set(&Any::ArrayInt32(vec![5, 6, 7, 8]));
+        assert!(matches!(var.get(), Any::ArrayInt32(v) if v == [5, 6, 7, 8]));
+        assert_eq!(unsafe { var.data.vec_i32.as_slice().as_ptr() }, before);
+
+        var.set(&Any::ArrayInt32(vec![9]));
+        assert!(matches!(var.get(), Any::ArrayInt32(v) if v == [9]));
+
+        // A different type still goes through destroy and construct
+        var.set(&Any::ArrayFloat(vec![1.5]));
+        assert_eq!(var.current(), Type::ArrayFloat);
+        assert!(matches!(var.get(), Any::ArrayFloat(v) if v == [1.5]));
+
+        let mut text = Var::new(&Any::from("hello"));
+        text.set(&Any::from("world"));
+        assert!(matches!(text.get(), Any::String(s) if s == "world"));
+    }
+
+    #[test]
+    fn test_type_try_from() {
+        assert_eq!(Type::try_from(Type::ArrayInt32.as_u8()), Ok(Type::ArrayInt32));
+        assert_eq!(Type::try_from(0u8), Ok(Type::Invalid));
+        assert_eq!(Type::try_from(Type::Matrix4x4 as usize), Ok(Type::Matrix4x4));
+
+        let past_end = Type::Matrix4x4 as usize + 1;
+        assert_eq!(Type::try_from(past_end), Err(InvalidType(past_end)));
+        assert_eq!(Type::try_from(255u8), Err(InvalidType(255)));
+        assert_eq!(InvalidType(255).to_string(), "invalid type discriminant 255");
+    }
+
+    #[test]
+    fn test_to_number() {
+        // Widening
+        assert_eq!(Any::Int32(-7).to_number::<i64>(), Some(-7));
+        assert_eq!(Any::UInt8(200).to_number::<f32>(), Some(200.0));
+        assert_eq!(Any::Float(1.5).to_number::<f64>(), Some(1.5));
+
+        // Narrowing in range
+        assert_eq!(Any::Int64(100).to_number::<i8>(), Some(100));
+        assert_eq!(Any::Double(42.9).to_number::<u16>(), Some(42));
+        assert_eq!(Any::Double(0.25).to_number::<f32>(), Some(0.25));
+
+        // Narrowing out of range
+        assert_eq!(Any::Int64(300).to_number::<u8>(), None);
+        assert_eq!(Any::Int32(-1).to_number::<u32>(), None);
+        assert_eq!(Any::UInt64(u64::MAX).to_number::<i64>(), None);
+        assert_eq!(Any::Double(1e40).to_number::<f32>(), None);
+        assert_eq!(Any::Float(f32::NAN).to_number::<i32>(), None);
+        assert_eq!(Any::Double(1e20).to_number::<i64>(), None);
+
+        assert_eq!(Any::Bool(true).to_number::<i32>(), None);
+    }
+
+    #[test]
+    fn test_array_mut() {
+        let _guard = crate::mock::lock_globals();
+        crate::mock::install();
+
+        let mut var = Var::new(&Any::ArrayInt32(vec![1, 2, 3]));
+        let before = unsafe { var.data.vec_i32.as_slice().as_ptr() };
+        assert_eq!(var.with_array_mut(|arr: &mut Arr<i32>| arr[1] = 20), Some(()));
+        assert!(matches!(var.get(), Any::ArrayInt32(v) if v == [1, 20, 3]));
+        assert_eq!(unsafe { var.data.vec_i32.as_slice().as_ptr() }, before);
+
+        // The element type has to match the active variant
+        assert!(var.array_mut::<i64>().is_none());
+        assert!(var.array_mut::<u32>().is_none());
+
+        let mut chars = Var::new(&Any::ArrayChar8(vec![65, 66]));
+        chars.array_mut::<i8>().unwrap()[0] = 67;
+        assert!(matches!(chars.get(), Any::ArrayChar8(v) if v == [67, 66]));
+
+        assert!(Var::new(&Any::Int32(1)).array_mut::<i32>().is_none());
+    }
+
+    #[test]
+    fn test_default_for() {
+        assert!(matches!(Any::default_for(Type::Int32), Any::Int32(0)));
+        assert!(matches!(Any::default_for(Type::Bool), Any::Bool(false)));
+        assert!(matches!(Any::default_for(Type::Double), Any::Double(v) if v == 0.0));
+        assert!(matches!(Any::default_for(Type::String), Any::String(s) if s.is_empty()));
+        assert!(matches!(Any::default_for(Type::ArrayFloat), Any::ArrayFloat(v) if v.is_empty()));
+        assert!(matches!(Any::default_for(Type::Vector3), Any::Vector3(v) if v == Vec3::zero()));
+        assert!(matches!(Any::default_for(Type::Invalid), Any::Invalid));
+        assert!(matches!(Any::default_for(Type::Function), Any::Invalid));
+
+        // Every type an Any can hold round-trips through its default
+        for ty in TYPES {
+            let value = Any::default_for(ty);
+            assert!(matches!(value, Any::Invalid) || value.current() == ty, "{:?}", ty);
+        }
+    }
+}
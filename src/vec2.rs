@@ -61,6 +61,9 @@ impl Vec2 {
     /// Add another vector to this one
     ///
     /// **Deprecated:** Use the `+` operator instead: `v1 + v2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the + operator instead")]
     #[must_use]
     pub fn add(&self, vector: Vec2) -> Vec2 {
@@ -70,6 +73,9 @@ impl Vec2 {
     /// Subtract another vector from this one
     ///
     /// **Deprecated:** Use the `-` operator instead: `v1 - v2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the - operator instead")]
     #[must_use]
     pub fn subtract(&self, vector: Vec2) -> Vec2 {
@@ -79,6 +85,9 @@ impl Vec2 {
     /// Multiply this vector by a scalar
     ///
     /// **Deprecated:** Use the `*` operator instead: `v * scalar`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the * operator instead")]
     #[must_use]
     pub fn scale(&self, scalar: f32) -> Vec2 {
@@ -131,6 +140,40 @@ impl Vec2 {
         Some(*self / mag)
     }
 
+    /// Normalize the vector to unit length
+    ///
+    /// Returns `fallback` if the magnitude is too small (near zero).
+    #[must_use]
+    pub fn normalize_or(&self, fallback: Vec2) -> Vec2 {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Normalize the vector to unit length
+    ///
+    /// Returns a zero vector if the magnitude is too small (near zero).
+    /// Same as `normalize()`, but spells out the degenerate behavior at the call site.
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Vec2 {
+        self.normalize_or(Vec2::zero())
+    }
+
+    /// Normalize the vector to unit length in place
+    ///
+    /// Returns false if the magnitude is too small (near zero), in which case the
+    /// vector is set to zero, matching `normalize()`.
+    pub fn normalize_in_place(&mut self) -> bool {
+        match self.try_normalize() {
+            Some(unit) => {
+                *self = unit;
+                true
+            }
+            None => {
+                *self = Vec2::zero();
+                false
+            }
+        }
+    }
+
     /// Calculate the dot product with another vector
     #[must_use]
     pub fn dot(&self, vector: Vec2) -> f32 {
@@ -146,6 +189,28 @@ impl Vec2 {
         self.x * vector.y - self.y * vector.x
     }
 
+    /// Calculate the signed area of a polygon using the shoelace formula
+    ///
+    /// The area is positive when the vertices wind counter-clockwise and
+    /// negative when they wind clockwise (with +y up). The polygon is closed
+    /// implicitly; fewer than 3 points give 0.
+    #[must_use]
+    pub fn signed_area(points: &[Vec2]) -> f32 {
+        if points.len() < 3 {
+            return 0.0;
+        }
+        let next = points.iter().cycle().skip(1);
+        points.iter().zip(next).map(|(a, b)| a.cross(*b)).sum::<f32>() * 0.5
+    }
+
+    /// Check if a polygon's vertices wind clockwise (with +y up)
+    ///
+    /// Degenerate polygons with zero area are not clockwise.
+    #[must_use]
+    pub fn is_clockwise(points: &[Vec2]) -> bool {
+        Self::signed_area(points) < 0.0
+    }
+
     /// Calculate the distance to another vector
     #[must_use]
     pub fn distance_to(&self, vector: Vec2) -> f32 {
@@ -160,12 +225,47 @@ impl Vec2 {
         (*self - vector).magnitude_squared()
     }
 
+    /// Find the closest point to this one on the infinite line through `a` and `b`
+    ///
+    /// Returns `a` if `a` and `b` coincide.
+    #[must_use]
+    pub fn closest_point_on_line(&self, a: Vec2, b: Vec2) -> Vec2 {
+        let ab = b - a;
+        let len_sq = ab.magnitude_squared();
+        if len_sq < EPSILON {
+            return a;
+        }
+        a + ab * ((*self - a).dot(ab) / len_sq)
+    }
+
+    /// Find the closest point to this one on the segment from `a` to `b`
+    ///
+    /// Like `closest_point_on_line`, but clamped to the segment's endpoints.
+    #[must_use]
+    pub fn closest_point_on_segment(&self, a: Vec2, b: Vec2) -> Vec2 {
+        let ab = b - a;
+        let len_sq = ab.magnitude_squared();
+        if len_sq < EPSILON {
+            return a;
+        }
+        let t = ((*self - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+        a + ab * t
+    }
+
+    /// Calculate the distance from this point to the segment from `a` to `b`
+    #[must_use]
+    pub fn distance_to_segment(&self, a: Vec2, b: Vec2) -> f32 {
+        self.distance_to(self.closest_point_on_segment(a, b))
+    }
+
     /// Linear interpolation between this vector and another
     ///
-    /// `t` should be between 0.0 and 1.0:
     /// - t = 0.0 returns self
     /// - t = 1.0 returns other
     /// - t = 0.5 returns the midpoint
+    ///
+    /// `t` is not clamped: values outside [0, 1] extrapolate past the endpoints,
+    /// which is useful for prediction. Use `lerp_clamped` to stay between them.
     #[must_use]
     pub fn lerp(&self, other: Vec2, t: f32) -> Vec2 {
         Vec2 {
@@ -174,6 +274,28 @@ impl Vec2 {
         }
     }
 
+    /// Linear interpolation with `t` clamped to [0, 1]
+    ///
+    /// Never leaves the segment between self and other.
+    #[must_use]
+    pub fn lerp_clamped(&self, other: Vec2, t: f32) -> Vec2 {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
+    /// Find the `t` for which `a.lerp(b, t)` is closest to `value`
+    ///
+    /// Points on the line through `a` and `b` round-trip exactly; other points
+    /// are projected onto it first. Returns 0.0 if `a` and `b` coincide.
+    #[must_use]
+    pub fn inverse_lerp(a: Vec2, b: Vec2, value: Vec2) -> f32 {
+        let ab = b - a;
+        let len_sq = ab.magnitude_squared();
+        if len_sq < EPSILON * EPSILON {
+            return 0.0;
+        }
+        (value - a).dot(ab) / len_sq
+    }
+
     /// Clamp the vector's magnitude to a maximum value
     #[must_use]
     pub fn clamp_magnitude(&self, max_magnitude: f32) -> Vec2 {
@@ -188,6 +310,27 @@ impl Vec2 {
         }
     }
 
+    /// Lengthen the vector to at least `min_magnitude`, preserving direction
+    ///
+    /// A zero vector has no direction to lengthen along and is returned unchanged.
+    #[must_use]
+    pub fn clamp_length_min(&self, min_magnitude: f32) -> Vec2 {
+        let mag = self.magnitude();
+        if mag >= min_magnitude || mag < EPSILON {
+            *self
+        } else {
+            *self * (min_magnitude / mag)
+        }
+    }
+
+    /// Clamp the magnitude of the vector to `[min, max]`, preserving direction
+    ///
+    /// A zero vector is returned unchanged.
+    #[must_use]
+    pub fn clamp_length(&self, min: f32, max: f32) -> Vec2 {
+        self.clamp_length_min(min).clamp_magnitude(max)
+    }
+
     /// Reflect the vector across a normal
     ///
     /// The normal should be normalized for correct results.
@@ -243,6 +386,33 @@ impl Vec2 {
         }
     }
 
+    /// Rotate this point about `pivot` by an angle (in radians, counter-clockwise)
+    #[must_use]
+    pub fn rotate_around(&self, pivot: Vec2, angle: f32) -> Vec2 {
+        pivot + (*self - pivot).rotate(angle)
+    }
+
+    /// Turn the vector toward `target` by at most `max_radians`, keeping its magnitude
+    ///
+    /// Unlike `slerp`, the step is limited by angle rather than a fraction, and
+    /// never overshoots: if `target` is within `max_radians` its direction is
+    /// returned. An antiparallel target is approached counter-clockwise.
+    /// Zero vectors are returned unchanged.
+    #[must_use]
+    pub fn rotate_towards(&self, target: Vec2, max_radians: f32) -> Vec2 {
+        let mag = self.magnitude();
+        if mag < EPSILON || target.is_zero() {
+            return *self;
+        }
+
+        // Signed angle from self to target, in (-π, π]
+        let delta = self.cross(target).atan2(self.dot(target));
+        if delta.abs() <= max_radians {
+            return target.normalize() * mag;
+        }
+        self.rotate(max_radians.copysign(delta))
+    }
+
     /// Get a perpendicular vector (rotated 90 degrees counter-clockwise)
     #[must_use]
     pub fn perpendicular(&self) -> Vec2 {
@@ -254,10 +424,17 @@ impl Vec2 {
 
     /// Check if this vector is approximately equal to another
     ///
-    /// Uses an epsilon value for floating point comparison
+    /// Uses an epsilon value for floating point comparison; see `approx_eq_eps`
+    /// to pick the tolerance
     #[must_use]
     pub fn approx_eq(&self, other: Vec2) -> bool {
-        (self.x - other.x).abs() < EPSILON && (self.y - other.y).abs() < EPSILON
+        self.approx_eq_eps(other, EPSILON)
+    }
+
+    /// Check if every component differs from `other`'s by less than `epsilon`
+    #[must_use]
+    pub fn approx_eq_eps(&self, other: Vec2, epsilon: f32) -> bool {
+        (self.x - other.x).abs() < epsilon && (self.y - other.y).abs() < epsilon
     }
 
     /// Check if this vector is approximately zero
@@ -272,6 +449,18 @@ impl Vec2 {
         (self.magnitude_squared() - 1.0).abs() < EPSILON
     }
 
+    /// Check if all components are finite (neither NaN nor infinite)
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Check if any component is NaN
+    #[must_use]
+    pub fn has_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
     /// Get a specific component by index (0 = x, 1 = y)
     ///
     /// Returns None if index is out of bounds
@@ -323,6 +512,48 @@ impl Vec2 {
         }
     }
 
+    /// Compare each component for equal to `other`
+    ///
+    /// Uses exact comparison; see `approx_eq` for a tolerant check
+    #[must_use]
+    pub fn cmpeq(&self, other: Vec2) -> (bool, bool) {
+        (self.x == other.x, self.y == other.y)
+    }
+
+    /// Compare each component for less than `other`
+    #[must_use]
+    pub fn cmplt(&self, other: Vec2) -> (bool, bool) {
+        (self.x < other.x, self.y < other.y)
+    }
+
+    /// Compare each component for less than or equal to `other`
+    #[must_use]
+    pub fn cmple(&self, other: Vec2) -> (bool, bool) {
+        (self.x <= other.x, self.y <= other.y)
+    }
+
+    /// Compare each component for greater than `other`
+    #[must_use]
+    pub fn cmpgt(&self, other: Vec2) -> (bool, bool) {
+        (self.x > other.x, self.y > other.y)
+    }
+
+    /// Compare each component for greater than or equal to `other`
+    #[must_use]
+    pub fn cmpge(&self, other: Vec2) -> (bool, bool) {
+        (self.x >= other.x, self.y >= other.y)
+    }
+
+    /// Pick each component from `a` where `mask` is true, otherwise from `b`
+    #[must_use]
+    pub fn select(mask: (bool, bool), a: Vec2, b: Vec2) -> Vec2 {
+        let (x, y) = mask;
+        Vec2 {
+            x: if x { a.x } else { b.x },
+            y: if y { a.y } else { b.y },
+        }
+    }
+
     /// Return the component-wise absolute value
     #[must_use]
     pub fn abs(&self) -> Vec2 {
@@ -331,6 +562,24 @@ impl Vec2 {
             y: self.y.abs(),
         }
     }
+
+    /// Apply `f` to each component
+    #[must_use]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Vec2 {
+        Vec2 {
+            x: f(self.x),
+            y: f(self.y),
+        }
+    }
+
+    /// Combine this vector with another component-wise using `f`
+    #[must_use]
+    pub fn zip_map<F: Fn(f32, f32) -> f32>(&self, other: Vec2, f: F) -> Vec2 {
+        Vec2 {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+        }
+    }
 }
 
 // ============================================
@@ -437,6 +686,32 @@ impl DivAssign<f32> for Vec2 {
     }
 }
 
+/// Component-wise sum, starting from zero
+impl std::iter::Sum for Vec2 {
+    fn sum<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::zero(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Vec2> for Vec2 {
+    fn sum<I: Iterator<Item = &'a Vec2>>(iter: I) -> Vec2 {
+        iter.copied().sum()
+    }
+}
+
+/// Component-wise product, starting from one
+impl std::iter::Product for Vec2 {
+    fn product<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::unit(), |acc, v| Vec2 { x: acc.x * v.x, y: acc.y * v.y })
+    }
+}
+
+impl<'a> std::iter::Product<&'a Vec2> for Vec2 {
+    fn product<I: Iterator<Item = &'a Vec2>>(iter: I) -> Vec2 {
+        iter.copied().product()
+    }
+}
+
 impl Index<usize> for Vec2 {
     type Output = f32;
 
@@ -489,6 +764,34 @@ impl From<Vec2> for [f32; 2] {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<glam::Vec2> for Vec2 {
+    fn from(v: glam::Vec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec2> for glam::Vec2 {
+    fn from(v: Vec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector2<f32>> for Vec2 {
+    fn from(v: nalgebra::Vector2<f32>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec2> for nalgebra::Vector2<f32> {
+    fn from(v: Vec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
 // ============================================
 // Tests
 // ============================================
@@ -548,4 +851,179 @@ mod tests {
         v *= 2.0;
         assert!(v.approx_eq(Vec2::new(4.0, 6.0)));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_normalize_or() {
+        let zero = Vec2::zero();
+        assert_eq!(zero.normalize_or(Vec2::up()), Vec2::up());
+        assert_eq!(zero.normalize_or_zero(), Vec2::zero());
+
+        let v = Vec2::new(3.0, 4.0);
+        assert!(v.normalize_or(Vec2::up()).approx_eq(Vec2::new(0.6, 0.8)));
+        assert!(v.normalize_or_zero().approx_eq(v.normalize()));
+    }
+
+    #[test]
+    fn test_finite_and_nan() {
+        let v = Vec2::new(1.0, 2.0);
+        assert!(v.is_finite());
+        assert!(!v.has_nan());
+
+        let mut nan = v;
+        nan.y = f32::NAN;
+        assert!(!nan.is_finite());
+        assert!(nan.has_nan());
+
+        let mut inf = v;
+        inf.x = f32::INFINITY;
+        assert!(!inf.is_finite());
+        assert!(!inf.has_nan());
+    }
+
+    #[test]
+    fn test_comparison_masks() {
+        let a = Vec2::new(1.0, 5.0);
+        let b = Vec2::new(2.0, 4.0);
+        assert_eq!(a.cmplt(b), (true, false));
+        assert_eq!(a.cmpge(b), (false, true));
+        assert_eq!(a.cmpeq(b), (false, false));
+
+        // Branchless component-wise minimum
+        assert_eq!(Vec2::select(a.cmplt(b), a, b), a.min(b));
+        assert_eq!(Vec2::select(a.cmpgt(b), a, b), a.max(b));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let v = Vec2::new(3.0, 4.0);
+
+        let below = (v * 0.2).clamp_length(2.0, 10.0);
+        assert!((below.magnitude() - 2.0).abs() < EPSILON);
+        assert!(below.normalize().approx_eq(v.normalize()));
+
+        assert!(v.clamp_length(2.0, 10.0).approx_eq(v));
+
+        let above = (v * 4.0).clamp_length(2.0, 10.0);
+        assert!((above.magnitude() - 10.0).abs() < 1e-5);
+        assert!(above.normalize().approx_eq(v.normalize()));
+
+        assert!(Vec2::zero().clamp_length_min(1.0).is_zero());
+    }
+
+    #[test]
+    fn test_segment_distance() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(4.0, 0.0);
+
+        // Projects inside the segment
+        let p = Vec2::new(1.0, 3.0);
+        assert!(p.closest_point_on_segment(a, b).approx_eq(Vec2::new(1.0, 0.0)));
+        assert!((p.distance_to_segment(a, b) - 3.0).abs() < EPSILON);
+
+        // Projects beyond b: the segment clamps, the line does not
+        let p = Vec2::new(7.0, 4.0);
+        assert!(p.closest_point_on_segment(a, b).approx_eq(b));
+        assert!((p.distance_to_segment(a, b) - 5.0).abs() < EPSILON);
+        assert!(p.closest_point_on_line(a, b).approx_eq(Vec2::new(7.0, 0.0)));
+    }
+
+    #[test]
+    fn test_rotate_towards() {
+        use std::f32::consts::FRAC_PI_6;
+
+        let v = Vec2::new(1.0, 0.0);
+        let turned = v.rotate_towards(Vec2::new(0.0, 3.0), FRAC_PI_6);
+        assert!(turned.approx_eq(Vec2::new(FRAC_PI_6.cos(), FRAC_PI_6.sin())));
+
+        // Clockwise targets turn clockwise
+        let cw = v.rotate_towards(Vec2::new(0.0, -1.0), FRAC_PI_6);
+        assert!(cw.approx_eq(Vec2::new(FRAC_PI_6.cos(), -FRAC_PI_6.sin())));
+
+        let reached = v.rotate_towards(Vec2::new(1.0, 1.0), 1.0);
+        assert!(reached.approx_eq(Vec2::new(1.0, 1.0).normalize()));
+
+        assert!(v.rotate_towards(v, FRAC_PI_6).approx_eq(v));
+        let away = v.rotate_towards(Vec2::new(-2.0, 0.0), FRAC_PI_6);
+        assert!((away.angle_to(v) - FRAC_PI_6).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let v = [Vec2::new(1.0, 2.0), Vec2::new(3.0, -4.0)];
+        assert_eq!(v.iter().sum::<Vec2>(), Vec2::new(4.0, -2.0));
+        assert_eq!(v.iter().copied().product::<Vec2>(), Vec2::new(3.0, -8.0));
+    }
+
+    #[test]
+    fn test_normalize_in_place() {
+        let mut v = Vec2::new(3.0, 4.0);
+        assert!(v.normalize_in_place());
+        assert_eq!(v, Vec2::new(3.0, 4.0).normalize());
+
+        let mut zero = Vec2::zero();
+        assert!(!zero.normalize_in_place());
+        assert_eq!(zero, Vec2::zero());
+    }
+
+    #[test]
+    fn test_map_and_zip_map() {
+        let v = Vec2::new(-3.0, 2.0);
+        assert_eq!(v.map(|c| c * c), Vec2::new(9.0, 4.0));
+        assert_eq!(v.zip_map(Vec2::new(1.0, 1.0), f32::max), v.max(Vec2::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_lerp_clamped() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(2.0, 4.0);
+
+        assert_eq!(a.lerp(b, -0.5), Vec2::new(-1.0, -2.0));
+        assert_eq!(a.lerp_clamped(b, -0.5), a);
+        assert_eq!(a.lerp_clamped(b, 1.5), b);
+        assert_eq!(a.lerp_clamped(b, 0.25), a.lerp(b, 0.25));
+
+        assert!((Vec2::inverse_lerp(a, b, a.lerp(b, 0.75)) - 0.75).abs() < 1e-6);
+        assert!((Vec2::inverse_lerp(a, b, a.lerp(b, -0.5)) + 0.5).abs() < 1e-6);
+        assert_eq!(Vec2::inverse_lerp(a, a, b), 0.0);
+    }
+
+    #[test]
+    fn test_polygon_winding() {
+        let square = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_eq!(Vec2::signed_area(&square), 4.0);
+        assert!(!Vec2::is_clockwise(&square));
+
+        let mut reversed = square;
+        reversed.reverse();
+        assert_eq!(Vec2::signed_area(&reversed), -4.0);
+        assert!(Vec2::is_clockwise(&reversed));
+
+        assert_eq!(Vec2::signed_area(&square[..2]), 0.0);
+        assert!(!Vec2::is_clockwise(&[]));
+    }
+
+    #[test]
+    fn test_approx_eq_eps() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(1.0, 2.0001);
+        assert!(!a.approx_eq(b));
+        assert!(a.approx_eq_eps(b, 1e-3));
+        assert!(!a.approx_eq_eps(b, 1e-5));
+    }
+
+    #[test]
+    fn test_rotate_around() {
+        use std::f32::consts::FRAC_PI_2;
+
+        let pivot = Vec2::new(2.0, 1.0);
+        let p = Vec2::new(3.0, 1.0);
+        assert!(p.rotate_around(pivot, FRAC_PI_2).approx_eq(Vec2::new(2.0, 2.0)));
+        assert!(p.rotate_around(pivot, -FRAC_PI_2).approx_eq(Vec2::new(2.0, 0.0)));
+        assert!(pivot.rotate_around(pivot, 1.0).approx_eq(pivot));
+    }
+}
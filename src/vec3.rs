@@ -1,5 +1,6 @@
 use std::fmt;
 use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut, AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::Vec2;
 
 /// A 3D vector with f32 components
 ///
@@ -74,6 +75,9 @@ impl Vec3 {
     /// Add another vector to this one
     ///
     /// **Deprecated:** Use the `+` operator instead: `v1 + v2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the + operator instead")]
     #[must_use]
     pub fn add(&self, vector: Vec3) -> Vec3 {
@@ -83,6 +87,9 @@ impl Vec3 {
     /// Subtract another vector from this one
     ///
     /// **Deprecated:** Use the `-` operator instead: `v1 - v2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the - operator instead")]
     #[must_use]
     pub fn subtract(&self, vector: Vec3) -> Vec3 {
@@ -92,6 +99,9 @@ impl Vec3 {
     /// Multiply this vector by a scalar
     ///
     /// **Deprecated:** Use the `*` operator instead: `v * scalar`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the * operator instead")]
     #[must_use]
     pub fn scale(&self, scalar: f32) -> Vec3 {
@@ -144,6 +154,40 @@ impl Vec3 {
         Some(*self / mag)
     }
 
+    /// Normalize the vector to unit length
+    ///
+    /// Returns `fallback` if the magnitude is too small (near zero).
+    #[must_use]
+    pub fn normalize_or(&self, fallback: Vec3) -> Vec3 {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Normalize the vector to unit length
+    ///
+    /// Returns a zero vector if the magnitude is too small (near zero).
+    /// Same as `normalize()`, but spells out the degenerate behavior at the call site.
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Vec3 {
+        self.normalize_or(Vec3::zero())
+    }
+
+    /// Normalize the vector to unit length in place
+    ///
+    /// Returns false if the magnitude is too small (near zero), in which case the
+    /// vector is set to zero, matching `normalize()`.
+    pub fn normalize_in_place(&mut self) -> bool {
+        match self.try_normalize() {
+            Some(unit) => {
+                *self = unit;
+                true
+            }
+            None => {
+                *self = Vec3::zero();
+                false
+            }
+        }
+    }
+
     /// Calculate the dot product with another vector
     #[must_use]
     pub fn dot(&self, vector: Vec3) -> f32 {
@@ -177,12 +221,47 @@ impl Vec3 {
         (*self - vector).magnitude_squared()
     }
 
+    /// Find the closest point to this one on the infinite line through `a` and `b`
+    ///
+    /// Returns `a` if `a` and `b` coincide.
+    #[must_use]
+    pub fn closest_point_on_line(&self, a: Vec3, b: Vec3) -> Vec3 {
+        let ab = b - a;
+        let len_sq = ab.magnitude_squared();
+        if len_sq < EPSILON {
+            return a;
+        }
+        a + ab * ((*self - a).dot(ab) / len_sq)
+    }
+
+    /// Find the closest point to this one on the segment from `a` to `b`
+    ///
+    /// Like `closest_point_on_line`, but clamped to the segment's endpoints.
+    #[must_use]
+    pub fn closest_point_on_segment(&self, a: Vec3, b: Vec3) -> Vec3 {
+        let ab = b - a;
+        let len_sq = ab.magnitude_squared();
+        if len_sq < EPSILON {
+            return a;
+        }
+        let t = ((*self - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+        a + ab * t
+    }
+
+    /// Calculate the distance from this point to the segment from `a` to `b`
+    #[must_use]
+    pub fn distance_to_segment(&self, a: Vec3, b: Vec3) -> f32 {
+        self.distance_to(self.closest_point_on_segment(a, b))
+    }
+
     /// Linear interpolation between this vector and another
     ///
-    /// `t` should be between 0.0 and 1.0:
     /// - t = 0.0 returns self
     /// - t = 1.0 returns other
     /// - t = 0.5 returns the midpoint
+    ///
+    /// `t` is not clamped: values outside [0, 1] extrapolate past the endpoints,
+    /// which is useful for prediction. Use `lerp_clamped` to stay between them.
     #[must_use]
     pub fn lerp(&self, other: Vec3, t: f32) -> Vec3 {
         Vec3 {
@@ -192,6 +271,55 @@ impl Vec3 {
         }
     }
 
+    /// Linear interpolation with `t` clamped to [0, 1]
+    ///
+    /// Never leaves the segment between self and other.
+    #[must_use]
+    pub fn lerp_clamped(&self, other: Vec3, t: f32) -> Vec3 {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
+    /// Find the `t` for which `a.lerp(b, t)` is closest to `value`
+    ///
+    /// Points on the line through `a` and `b` round-trip exactly; other points
+    /// are projected onto it first. Returns 0.0 if `a` and `b` coincide.
+    #[must_use]
+    pub fn inverse_lerp(a: Vec3, b: Vec3, value: Vec3) -> f32 {
+        let ab = b - a;
+        let len_sq = ab.magnitude_squared();
+        if len_sq < EPSILON * EPSILON {
+            return 0.0;
+        }
+        (value - a).dot(ab) / len_sq
+    }
+
+    /// Cubic Hermite interpolation from `p0` to `p1` with tangents `m0` and `m1`
+    ///
+    /// Uses the standard basis `h00 = 2t³ - 3t² + 1`, `h10 = t³ - 2t² + t`,
+    /// `h01 = -2t³ + 3t²`, `h11 = t³ - t²`. Returns `p0` at t = 0 and `p1` at t = 1.
+    #[must_use]
+    pub fn hermite(p0: Vec3, m0: Vec3, p1: Vec3, m1: Vec3, t: f32) -> Vec3 {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+        p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+    }
+
+    /// Catmull-Rom spline interpolation between `p1` and `p2`
+    ///
+    /// `p0` and `p3` are the neighbouring control points that shape the
+    /// tangents, so consecutive segments of a path join smoothly. Returns `p1`
+    /// at t = 0 and `p2` at t = 1.
+    #[must_use]
+    pub fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+        let m1 = (p2 - p0) * 0.5;
+        let m2 = (p3 - p1) * 0.5;
+        Self::hermite(p1, m1, p2, m2, t)
+    }
+
     /// Spherical linear interpolation between this vector and another
     ///
     /// This maintains constant speed on the unit sphere. Both vectors
@@ -227,6 +355,27 @@ impl Vec3 {
         }
     }
 
+    /// Lengthen the vector to at least `min_magnitude`, preserving direction
+    ///
+    /// A zero vector has no direction to lengthen along and is returned unchanged.
+    #[must_use]
+    pub fn clamp_length_min(&self, min_magnitude: f32) -> Vec3 {
+        let mag = self.magnitude();
+        if mag >= min_magnitude || mag < EPSILON {
+            *self
+        } else {
+            *self * (min_magnitude / mag)
+        }
+    }
+
+    /// Clamp the magnitude of the vector to `[min, max]`, preserving direction
+    ///
+    /// A zero vector is returned unchanged.
+    #[must_use]
+    pub fn clamp_length(&self, min: f32, max: f32) -> Vec3 {
+        self.clamp_length_min(min).clamp_magnitude(max)
+    }
+
     /// Reflect the vector across a normal
     ///
     /// The normal should be normalized for correct results.
@@ -275,14 +424,87 @@ impl Vec3 {
         *self * cos + axis.cross(*self) * sin + axis * (axis.dot(*self) * (1.0 - cos))
     }
 
+    /// Rotate this point about an axis through `pivot` by an angle (in radians)
+    ///
+    /// The axis should be normalized, as for `rotate_around_axis`.
+    #[must_use]
+    pub fn rotate_around_point(&self, pivot: Vec3, axis: Vec3, angle: f32) -> Vec3 {
+        pivot + (*self - pivot).rotate_around_axis(axis, angle)
+    }
+
+    /// Turn the vector toward `target` by at most `max_radians`, keeping its magnitude
+    ///
+    /// Unlike `slerp`, the step is limited by angle rather than a fraction, and
+    /// never overshoots: if `target` is within `max_radians` its direction is
+    /// returned. An antiparallel target is approached around an arbitrary
+    /// perpendicular axis. Zero vectors are returned unchanged.
+    #[must_use]
+    pub fn rotate_towards(&self, target: Vec3, max_radians: f32) -> Vec3 {
+        let mag = self.magnitude();
+        if mag < EPSILON || target.is_zero() {
+            return *self;
+        }
+        if self.angle_to(target) <= max_radians {
+            return target.normalize() * mag;
+        }
+
+        let mut axis = self.cross(target);
+        if axis.is_zero() {
+            axis = self.cross(Vec3::right());
+            if axis.is_zero() {
+                axis = self.cross(Vec3::up());
+            }
+        }
+        self.rotate_around_axis(axis.normalize(), max_radians)
+    }
+
+    /// Create a vector from spherical coordinates
+    ///
+    /// `theta` is the polar angle measured from the +y axis, in `[0, π]`, and
+    /// `phi` is the azimuth in the xz-plane measured from +x toward +z:
+    /// `(r·sinθ·cosφ, r·cosθ, r·sinθ·sinφ)`.
+    #[must_use]
+    pub fn from_spherical(radius: f32, theta: f32, phi: f32) -> Vec3 {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        Vec3 {
+            x: radius * sin_theta * cos_phi,
+            y: radius * cos_theta,
+            z: radius * sin_theta * sin_phi,
+        }
+    }
+
+    /// Convert to spherical coordinates `(radius, theta, phi)`
+    ///
+    /// Uses the conventions of `from_spherical`; `theta` is in `[0, π]` and
+    /// `phi` in `[-π, π]`. The origin returns all zeros, and points on the
+    /// y axis have `phi = 0`.
+    #[must_use]
+    pub fn to_spherical(&self) -> (f32, f32, f32) {
+        let radius = self.magnitude();
+        if radius < EPSILON {
+            return (0.0, 0.0, 0.0);
+        }
+        let theta = (self.y / radius).clamp(-1.0, 1.0).acos();
+        let phi = self.z.atan2(self.x);
+        (radius, theta, phi)
+    }
+
     /// Check if this vector is approximately equal to another
     ///
-    /// Uses an epsilon value for floating point comparison
+    /// Uses an epsilon value for floating point comparison; see `approx_eq_eps`
+    /// to pick the tolerance
     #[must_use]
     pub fn approx_eq(&self, other: Vec3) -> bool {
-        (self.x - other.x).abs() < EPSILON
-            && (self.y - other.y).abs() < EPSILON
-            && (self.z - other.z).abs() < EPSILON
+        self.approx_eq_eps(other, EPSILON)
+    }
+
+    /// Check if every component differs from `other`'s by less than `epsilon`
+    #[must_use]
+    pub fn approx_eq_eps(&self, other: Vec3, epsilon: f32) -> bool {
+        (self.x - other.x).abs() < epsilon
+            && (self.y - other.y).abs() < epsilon
+            && (self.z - other.z).abs() < epsilon
     }
 
     /// Check if this vector is approximately zero
@@ -297,6 +519,18 @@ impl Vec3 {
         (self.magnitude_squared() - 1.0).abs() < EPSILON
     }
 
+    /// Check if all components are finite (neither NaN nor infinite)
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Check if any component is NaN
+    #[must_use]
+    pub fn has_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
     /// Get a specific component by index (0 = x, 1 = y, 2 = z)
     ///
     /// Returns None if index is out of bounds
@@ -323,6 +557,26 @@ impl Vec3 {
         }
     }
 
+    /// Project onto the XY plane, dropping z
+    #[must_use]
+    pub fn xy(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    /// Project onto the XZ plane, dropping y
+    ///
+    /// This is the ground plane in Y-up worlds, e.g. for top-down movement.
+    #[must_use]
+    pub fn xz(&self) -> Vec2 {
+        Vec2::new(self.x, self.z)
+    }
+
+    /// Project onto the YZ plane, dropping x
+    #[must_use]
+    pub fn yz(&self) -> Vec2 {
+        Vec2::new(self.y, self.z)
+    }
+
     /// Return the component-wise minimum of two vectors
     #[must_use]
     pub fn min(&self, other: Vec3) -> Vec3 {
@@ -353,6 +607,49 @@ impl Vec3 {
         }
     }
 
+    /// Compare each component for equal to `other`
+    ///
+    /// Uses exact comparison; see `approx_eq` for a tolerant check
+    #[must_use]
+    pub fn cmpeq(&self, other: Vec3) -> (bool, bool, bool) {
+        (self.x == other.x, self.y == other.y, self.z == other.z)
+    }
+
+    /// Compare each component for less than `other`
+    #[must_use]
+    pub fn cmplt(&self, other: Vec3) -> (bool, bool, bool) {
+        (self.x < other.x, self.y < other.y, self.z < other.z)
+    }
+
+    /// Compare each component for less than or equal to `other`
+    #[must_use]
+    pub fn cmple(&self, other: Vec3) -> (bool, bool, bool) {
+        (self.x <= other.x, self.y <= other.y, self.z <= other.z)
+    }
+
+    /// Compare each component for greater than `other`
+    #[must_use]
+    pub fn cmpgt(&self, other: Vec3) -> (bool, bool, bool) {
+        (self.x > other.x, self.y > other.y, self.z > other.z)
+    }
+
+    /// Compare each component for greater than or equal to `other`
+    #[must_use]
+    pub fn cmpge(&self, other: Vec3) -> (bool, bool, bool) {
+        (self.x >= other.x, self.y >= other.y, self.z >= other.z)
+    }
+
+    /// Pick each component from `a` where `mask` is true, otherwise from `b`
+    #[must_use]
+    pub fn select(mask: (bool, bool, bool), a: Vec3, b: Vec3) -> Vec3 {
+        let (x, y, z) = mask;
+        Vec3 {
+            x: if x { a.x } else { b.x },
+            y: if y { a.y } else { b.y },
+            z: if z { a.z } else { b.z },
+        }
+    }
+
     /// Return the component-wise absolute value
     #[must_use]
     pub fn abs(&self) -> Vec3 {
@@ -363,6 +660,26 @@ impl Vec3 {
         }
     }
 
+    /// Apply `f` to each component
+    #[must_use]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Vec3 {
+        Vec3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    /// Combine this vector with another component-wise using `f`
+    #[must_use]
+    pub fn zip_map<F: Fn(f32, f32) -> f32>(&self, other: Vec3, f: F) -> Vec3 {
+        Vec3 {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+        }
+    }
+
     /// Calculate the triple scalar product (a · (b × c))
     ///
     /// This gives the signed volume of the parallelepiped formed by three vectors.
@@ -371,6 +688,15 @@ impl Vec3 {
         a.dot(b.cross(c))
     }
 
+    /// Calculate the vector triple product (a × (b × c))
+    ///
+    /// By the BAC-CAB identity this equals `b * (a · c) - c * (a · b)`, a vector
+    /// in the plane of `b` and `c`.
+    #[must_use]
+    pub fn vector_triple_product(a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+        a.cross(b.cross(c))
+    }
+
     /// Check if three vectors are coplanar (lie in the same plane)
     #[must_use]
     pub fn are_coplanar(a: Vec3, b: Vec3, c: Vec3) -> bool {
@@ -492,6 +818,32 @@ impl DivAssign<f32> for Vec3 {
     }
 }
 
+/// Component-wise sum, starting from zero
+impl std::iter::Sum for Vec3 {
+    fn sum<I: Iterator<Item = Vec3>>(iter: I) -> Vec3 {
+        iter.fold(Vec3::zero(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Vec3> for Vec3 {
+    fn sum<I: Iterator<Item = &'a Vec3>>(iter: I) -> Vec3 {
+        iter.copied().sum()
+    }
+}
+
+/// Component-wise product, starting from one
+impl std::iter::Product for Vec3 {
+    fn product<I: Iterator<Item = Vec3>>(iter: I) -> Vec3 {
+        iter.fold(Vec3::unit(), |acc, v| Vec3 { x: acc.x * v.x, y: acc.y * v.y, z: acc.z * v.z })
+    }
+}
+
+impl<'a> std::iter::Product<&'a Vec3> for Vec3 {
+    fn product<I: Iterator<Item = &'a Vec3>>(iter: I) -> Vec3 {
+        iter.copied().product()
+    }
+}
+
 impl Index<usize> for Vec3 {
     type Output = f32;
 
@@ -546,6 +898,34 @@ impl From<Vec3> for [f32; 3] {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for Vec3 {
+    fn from(v: glam::Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for glam::Vec3 {
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f32>> for Vec3 {
+    fn from(v: nalgebra::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec3> for nalgebra::Vector3<f32> {
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
 // ============================================
 // Tests
 // ============================================
@@ -618,4 +998,249 @@ mod tests {
         let rotated = v.rotate_around_axis(Vec3::up(), PI / 2.0);
         assert!(rotated.approx_eq(Vec3::forward()));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_normalize_or() {
+        let zero = Vec3::zero();
+        assert_eq!(zero.normalize_or(Vec3::up()), Vec3::up());
+        assert_eq!(zero.normalize_or_zero(), Vec3::zero());
+
+        let v = Vec3::new(0.0, 3.0, 4.0);
+        assert!(v.normalize_or(Vec3::up()).approx_eq(Vec3::new(0.0, 0.6, 0.8)));
+        assert!(v.normalize_or_zero().approx_eq(v.normalize()));
+    }
+
+    #[test]
+    fn test_finite_and_nan() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert!(v.is_finite());
+        assert!(!v.has_nan());
+
+        let mut nan = v;
+        nan.y = f32::NAN;
+        assert!(!nan.is_finite());
+        assert!(nan.has_nan());
+
+        let mut inf = v;
+        inf.x = f32::INFINITY;
+        assert!(!inf.is_finite());
+        assert!(!inf.has_nan());
+    }
+
+    #[test]
+    fn test_comparison_masks() {
+        let a = Vec3::new(1.0, 5.0, -3.0);
+        let b = Vec3::new(2.0, 4.0, 0.0);
+        assert_eq!(a.cmplt(b), (true, false, true));
+        assert_eq!(a.cmpge(b), (false, true, false));
+        assert_eq!(a.cmpeq(b), (false, false, false));
+
+        // Branchless component-wise minimum
+        assert_eq!(Vec3::select(a.cmplt(b), a, b), a.min(b));
+        assert_eq!(Vec3::select(a.cmpgt(b), a, b), a.max(b));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let v = Vec3::new(0.0, 3.0, 4.0);
+
+        let below = (v * 0.2).clamp_length(2.0, 10.0);
+        assert!((below.magnitude() - 2.0).abs() < EPSILON);
+        assert!(below.normalize().approx_eq(v.normalize()));
+
+        assert!(v.clamp_length(2.0, 10.0).approx_eq(v));
+
+        let above = (v * 4.0).clamp_length(2.0, 10.0);
+        assert!((above.magnitude() - 10.0).abs() < 1e-5);
+        assert!(above.normalize().approx_eq(v.normalize()));
+
+        assert!(Vec3::zero().clamp_length_min(1.0).is_zero());
+    }
+
+    #[test]
+    fn test_segment_distance() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 0.0, 4.0);
+
+        // Projects inside the segment
+        let p = Vec3::new(3.0, 0.0, 2.0);
+        assert!(p.closest_point_on_segment(a, b).approx_eq(Vec3::new(0.0, 0.0, 2.0)));
+        assert!((p.distance_to_segment(a, b) - 3.0).abs() < EPSILON);
+
+        // Projects before a: the segment clamps, the line does not
+        let p = Vec3::new(0.0, 4.0, -3.0);
+        assert!(p.closest_point_on_segment(a, b).approx_eq(a));
+        assert!((p.distance_to_segment(a, b) - 5.0).abs() < EPSILON);
+        assert!(p.closest_point_on_line(a, b).approx_eq(Vec3::new(0.0, 0.0, -3.0)));
+
+        // Degenerate segment
+        assert!(p.closest_point_on_segment(a, a).approx_eq(a));
+    }
+
+    #[test]
+    fn test_plane_projections() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.xz(), Vec2::new(1.0, 3.0));
+        assert_eq!(v.xy(), Vec2::new(1.0, 2.0));
+        assert_eq!(v.yz(), Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_rotate_towards() {
+        use std::f32::consts::FRAC_PI_6;
+
+        let v = Vec3::new(2.0, 0.0, 0.0);
+        let turned = v.rotate_towards(Vec3::new(0.0, 5.0, 0.0), FRAC_PI_6);
+        assert!((turned.angle_to(v) - FRAC_PI_6).abs() < 1e-5);
+        assert!(turned.approx_eq(Vec3::new(2.0 * FRAC_PI_6.cos(), 2.0 * FRAC_PI_6.sin(), 0.0)));
+
+        // Within reach: snaps to the target direction without overshooting
+        let reached = v.rotate_towards(Vec3::new(0.0, 0.0, 1.0), 2.0);
+        assert!(reached.approx_eq(Vec3::new(0.0, 0.0, 2.0)));
+
+        // Already aligned
+        assert!(v.rotate_towards(Vec3::new(1.0, 0.0, 0.0), FRAC_PI_6).approx_eq(v));
+
+        // Antiparallel still makes progress
+        let away = v.rotate_towards(Vec3::new(-1.0, 0.0, 0.0), FRAC_PI_6);
+        assert!((away.angle_to(v) - FRAC_PI_6).abs() < 1e-5);
+        assert!((away.magnitude() - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        assert!(Vec3::from_spherical(2.0, 0.0, 1.0).approx_eq(Vec3::new(0.0, 2.0, 0.0)));
+        assert!(Vec3::from_spherical(1.0, FRAC_PI_2, 0.0).approx_eq(Vec3::new(1.0, 0.0, 0.0)));
+        assert!(Vec3::from_spherical(1.0, FRAC_PI_2, FRAC_PI_2).approx_eq(Vec3::new(0.0, 0.0, 1.0)));
+
+        for v in [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, -0.25),
+            Vec3::new(0.0, -3.0, 0.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+        ] {
+            let (r, theta, phi) = v.to_spherical();
+            assert!((0.0..=PI).contains(&theta));
+            assert!((Vec3::from_spherical(r, theta, phi) - v).magnitude() < 1e-5, "{}", v);
+        }
+
+        assert_eq!(Vec3::zero().to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let points = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(3.0, 0.0, -3.0), Vec3::new(2.0, 4.0, 6.0)];
+        let centroid = points.iter().copied().sum::<Vec3>() / points.len() as f32;
+        assert_eq!(centroid, Vec3::new(2.0, 2.0, 2.0));
+        assert_eq!(points.iter().sum::<Vec3>(), Vec3::new(6.0, 6.0, 6.0));
+        assert_eq!(points.iter().product::<Vec3>(), Vec3::new(6.0, 0.0, -54.0));
+
+        let empty: [Vec3; 0] = [];
+        assert_eq!(empty.iter().sum::<Vec3>(), Vec3::zero());
+        assert_eq!(empty.iter().product::<Vec3>(), Vec3::unit());
+    }
+
+    #[test]
+    fn test_normalize_in_place() {
+        let mut v = Vec3::new(3.0, -4.0, 12.0);
+        let expected = v.normalize();
+        assert!(v.normalize_in_place());
+        assert_eq!(v, expected);
+
+        let mut tiny = Vec3::new(1e-9, 0.0, 0.0);
+        assert!(!tiny.normalize_in_place());
+        assert_eq!(tiny, Vec3::new(1e-9, 0.0, 0.0).normalize());
+    }
+
+    #[test]
+    fn test_map_and_zip_map() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+        assert_eq!(v.map(|c| c * c), Vec3::new(1.0, 4.0, 9.0));
+
+        let other = Vec3::new(0.0, 5.0, -1.0);
+        assert_eq!(v.zip_map(other, f32::max), v.max(other));
+    }
+
+    #[test]
+    fn test_vector_triple_product() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(-2.0, 0.5, 4.0);
+        let c = Vec3::new(3.0, -1.0, 0.25);
+
+        let bac_cab = b * a.dot(c) - c * a.dot(b);
+        assert!((Vec3::vector_triple_product(a, b, c) - bac_cab).magnitude() < 1e-4);
+        assert!(Vec3::vector_triple_product(Vec3::right(), Vec3::right(), Vec3::up()).approx_eq(Vec3::down()));
+    }
+
+    #[test]
+    fn test_lerp_clamped() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(2.0, 4.0, -2.0);
+
+        assert_eq!(a.lerp(b, -0.5), Vec3::new(-1.0, -2.0, 1.0));
+        assert_eq!(a.lerp_clamped(b, -0.5), a);
+        assert_eq!(a.lerp_clamped(b, 1.5), b);
+        assert_eq!(a.lerp_clamped(b, 0.25), a.lerp(b, 0.25));
+
+        assert!((Vec3::inverse_lerp(a, b, a.lerp(b, 0.75)) - 0.75).abs() < 1e-6);
+        assert!((Vec3::inverse_lerp(a, b, a.lerp(b, -0.5)) + 0.5).abs() < 1e-6);
+        assert_eq!(Vec3::inverse_lerp(a, a, b), 0.0);
+    }
+
+    #[test]
+    fn test_splines() {
+        let (p0, p1, p2, p3) = (Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 0.0), Vec3::new(3.0, 2.0, 1.0), Vec3::new(4.0, 0.0, 1.0));
+
+        assert!(Vec3::catmull_rom(p0, p1, p2, p3, 0.0).approx_eq(p1));
+        assert!(Vec3::catmull_rom(p0, p1, p2, p3, 1.0).approx_eq(p2));
+
+        // Evenly spaced collinear points give plain linear motion
+        let step = p2 - p1;
+        let mid = Vec3::catmull_rom(p1 - step, p1, p2, p2 + step, 0.5);
+        assert!(mid.approx_eq(p1.lerp(p2, 0.5)));
+
+        let m = Vec3::zero();
+        assert!(Vec3::hermite(p1, m, p2, m, 0.0).approx_eq(p1));
+        assert!(Vec3::hermite(p1, m, p2, m, 1.0).approx_eq(p2));
+        assert!(Vec3::hermite(p1, m, p2, m, 0.5).approx_eq(p1.lerp(p2, 0.5)));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam_round_trip() {
+        let v = Vec3::new(1.0, -2.5, 3.0);
+        let g: glam::Vec3 = v.into();
+        assert_eq!(g, glam::Vec3::new(1.0, -2.5, 3.0));
+        assert_eq!(Vec3::from(g), v);
+        assert_eq!(Vec3::from(g.cross(glam::Vec3::X)), v.cross(Vec3::new(1.0, 0.0, 0.0)));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra_round_trip() {
+        let v = Vec3::new(1.0, -2.5, 3.0);
+        let n: nalgebra::Vector3<f32> = v.into();
+        assert_eq!(n, nalgebra::Vector3::new(1.0, -2.5, 3.0));
+        assert_eq!(Vec3::from(n), v);
+    }
+
+    #[test]
+    fn test_approx_eq_eps() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(1.0, 2.0001, 3.0);
+        assert!(!a.approx_eq(b));
+        assert!(a.approx_eq_eps(b, 1e-3));
+        assert!(!a.approx_eq_eps(b, 1e-5));
+    }
+
+    #[test]
+    fn test_rotate_around_point() {
+        let pivot = Vec3::new(1.0, 1.0, 5.0);
+        let p = Vec3::new(2.0, 1.0, 5.0);
+        let turned = p.rotate_around_point(pivot, Vec3::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+        assert!(turned.approx_eq(Vec3::new(1.0, 2.0, 5.0)));
+        assert!(pivot.rotate_around_point(pivot, Vec3::up(), 1.0).approx_eq(pivot));
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt;
 use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut, AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::Vec3;
 
 /// A 4D vector with f32 components
 ///
@@ -58,6 +59,18 @@ impl Vec4 {
         Self { x, y, z, w: 0.0 }
     }
 
+    /// Create a homogeneous position vector from a 3D point (w = 1)
+    #[must_use]
+    pub const fn from_point(point: Vec3) -> Self {
+        Self::position(point.x, point.y, point.z)
+    }
+
+    /// Create a homogeneous direction vector from a 3D vector (w = 0)
+    #[must_use]
+    pub const fn from_direction(direction: Vec3) -> Self {
+        Self::direction(direction.x, direction.y, direction.z)
+    }
+
     /// Create an RGBA color vector
     ///
     /// Components should typically be in the range [0.0, 1.0]
@@ -69,6 +82,9 @@ impl Vec4 {
     /// Add another vector to this one
     ///
     /// **Deprecated:** Use the `+` operator instead: `v1 + v2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the + operator instead")]
     #[must_use]
     pub fn add(&self, vector: Vec4) -> Vec4 {
@@ -78,6 +94,9 @@ impl Vec4 {
     /// Subtract another vector from this one
     ///
     /// **Deprecated:** Use the `-` operator instead: `v1 - v2`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the - operator instead")]
     #[must_use]
     pub fn subtract(&self, vector: Vec4) -> Vec4 {
@@ -87,6 +106,9 @@ impl Vec4 {
     /// Multiply this vector by a scalar
     ///
     /// **Deprecated:** Use the `*` operator instead: `v * scalar`
+    ///
+    /// Requires the `deprecated-methods` feature; removed in 2.0.0.
+    #[cfg(feature = "deprecated-methods")]
     #[deprecated(since = "0.1.0", note = "Use the * operator instead")]
     #[must_use]
     pub fn scale(&self, scalar: f32) -> Vec4 {
@@ -139,12 +161,67 @@ impl Vec4 {
         Some(*self / mag)
     }
 
+    /// Normalize the vector to unit length
+    ///
+    /// Returns `fallback` if the magnitude is too small (near zero).
+    #[must_use]
+    pub fn normalize_or(&self, fallback: Vec4) -> Vec4 {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Normalize the vector to unit length
+    ///
+    /// Returns a zero vector if the magnitude is too small (near zero).
+    /// Same as `normalize()`, but spells out the degenerate behavior at the call site.
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Vec4 {
+        self.normalize_or(Vec4::zero())
+    }
+
+    /// Normalize the vector to unit length in place
+    ///
+    /// Returns false if the magnitude is too small (near zero), in which case the
+    /// vector is set to zero, matching `normalize()`.
+    pub fn normalize_in_place(&mut self) -> bool {
+        match self.try_normalize() {
+            Some(unit) => {
+                *self = unit;
+                true
+            }
+            None => {
+                *self = Vec4::zero();
+                false
+            }
+        }
+    }
+
     /// Calculate the dot product with another vector
     #[must_use]
     pub fn dot(&self, vector: Vec4) -> f32 {
         self.x * vector.x + self.y * vector.y + self.z * vector.z + self.w * vector.w
     }
 
+    /// Calculate the dot product of the xyz components, ignoring w
+    ///
+    /// Use this when the vectors carry homogeneous 3D directions.
+    #[must_use]
+    pub fn dot3(&self, vector: Vec4) -> f32 {
+        self.x * vector.x + self.y * vector.y + self.z * vector.z
+    }
+
+    /// Calculate the cross product of the xyz components
+    ///
+    /// The result is a direction, so w is 0.
+    #[must_use]
+    pub fn cross3(&self, vector: Vec4) -> Vec4 {
+        Vec4 {
+            x: self.y * vector.z - self.z * vector.y,
+            y: self.z * vector.x - self.x * vector.z,
+            z: self.x * vector.y - self.y * vector.x,
+            w: 0.0,
+        }
+    }
+
     /// Calculate the distance to another vector
     #[must_use]
     pub fn distance_to(&self, vector: Vec4) -> f32 {
@@ -161,10 +238,12 @@ impl Vec4 {
 
     /// Linear interpolation between this vector and another
     ///
-    /// `t` should be between 0.0 and 1.0:
     /// - t = 0.0 returns self
     /// - t = 1.0 returns other
     /// - t = 0.5 returns the midpoint
+    ///
+    /// `t` is not clamped: values outside [0, 1] extrapolate past the endpoints,
+    /// which is useful for prediction. Use `lerp_clamped` to stay between them.
     #[must_use]
     pub fn lerp(&self, other: Vec4, t: f32) -> Vec4 {
         Vec4 {
@@ -175,6 +254,55 @@ impl Vec4 {
         }
     }
 
+    /// Linear interpolation with `t` clamped to [0, 1]
+    ///
+    /// Never leaves the segment between self and other.
+    #[must_use]
+    pub fn lerp_clamped(&self, other: Vec4, t: f32) -> Vec4 {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
+    /// Find the `t` for which `a.lerp(b, t)` is closest to `value`
+    ///
+    /// Points on the line through `a` and `b` round-trip exactly; other points
+    /// are projected onto it first. Returns 0.0 if `a` and `b` coincide.
+    #[must_use]
+    pub fn inverse_lerp(a: Vec4, b: Vec4, value: Vec4) -> f32 {
+        let ab = b - a;
+        let len_sq = ab.magnitude_squared();
+        if len_sq < EPSILON * EPSILON {
+            return 0.0;
+        }
+        (value - a).dot(ab) / len_sq
+    }
+
+    /// Cubic Hermite interpolation from `p0` to `p1` with tangents `m0` and `m1`
+    ///
+    /// Uses the standard basis `h00 = 2t³ - 3t² + 1`, `h10 = t³ - 2t² + t`,
+    /// `h01 = -2t³ + 3t²`, `h11 = t³ - t²`. Returns `p0` at t = 0 and `p1` at t = 1.
+    #[must_use]
+    pub fn hermite(p0: Vec4, m0: Vec4, p1: Vec4, m1: Vec4, t: f32) -> Vec4 {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+        p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+    }
+
+    /// Catmull-Rom spline interpolation between `p1` and `p2`
+    ///
+    /// `p0` and `p3` are the neighbouring control points that shape the
+    /// tangents, so consecutive segments of a path join smoothly. Returns `p1`
+    /// at t = 0 and `p2` at t = 1.
+    #[must_use]
+    pub fn catmull_rom(p0: Vec4, p1: Vec4, p2: Vec4, p3: Vec4, t: f32) -> Vec4 {
+        let m1 = (p2 - p0) * 0.5;
+        let m2 = (p3 - p1) * 0.5;
+        Self::hermite(p1, m1, p2, m2, t)
+    }
+
     /// Clamp the vector's magnitude to a maximum value
     #[must_use]
     pub fn clamp_magnitude(&self, max_magnitude: f32) -> Vec4 {
@@ -189,6 +317,27 @@ impl Vec4 {
         }
     }
 
+    /// Lengthen the vector to at least `min_magnitude`, preserving direction
+    ///
+    /// A zero vector has no direction to lengthen along and is returned unchanged.
+    #[must_use]
+    pub fn clamp_length_min(&self, min_magnitude: f32) -> Vec4 {
+        let mag = self.magnitude();
+        if mag >= min_magnitude || mag < EPSILON {
+            *self
+        } else {
+            *self * (min_magnitude / mag)
+        }
+    }
+
+    /// Clamp the magnitude of the vector to `[min, max]`, preserving direction
+    ///
+    /// A zero vector is returned unchanged.
+    #[must_use]
+    pub fn clamp_length(&self, min: f32, max: f32) -> Vec4 {
+        self.clamp_length_min(min).clamp_magnitude(max)
+    }
+
     /// Project this vector onto another vector
     #[must_use]
     pub fn project_onto(&self, other: Vec4) -> Vec4 {
@@ -219,13 +368,20 @@ impl Vec4 {
 
     /// Check if this vector is approximately equal to another
     ///
-    /// Uses an epsilon value for floating point comparison
+    /// Uses an epsilon value for floating point comparison; see `approx_eq_eps`
+    /// to pick the tolerance
     #[must_use]
     pub fn approx_eq(&self, other: Vec4) -> bool {
-        (self.x - other.x).abs() < EPSILON
-            && (self.y - other.y).abs() < EPSILON
-            && (self.z - other.z).abs() < EPSILON
-            && (self.w - other.w).abs() < EPSILON
+        self.approx_eq_eps(other, EPSILON)
+    }
+
+    /// Check if every component differs from `other`'s by less than `epsilon`
+    #[must_use]
+    pub fn approx_eq_eps(&self, other: Vec4, epsilon: f32) -> bool {
+        (self.x - other.x).abs() < epsilon
+            && (self.y - other.y).abs() < epsilon
+            && (self.z - other.z).abs() < epsilon
+            && (self.w - other.w).abs() < epsilon
     }
 
     /// Check if this vector is approximately zero
@@ -240,6 +396,18 @@ impl Vec4 {
         (self.magnitude_squared() - 1.0).abs() < EPSILON
     }
 
+    /// Check if all components are finite (neither NaN nor infinite)
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Check if any component is NaN
+    #[must_use]
+    pub fn has_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
     /// Get a specific component by index (0 = x, 1 = y, 2 = z, 3 = w)
     ///
     /// Returns None if index is out of bounds
@@ -301,6 +469,50 @@ impl Vec4 {
         }
     }
 
+    /// Compare each component for equal to `other`
+    ///
+    /// Uses exact comparison; see `approx_eq` for a tolerant check
+    #[must_use]
+    pub fn cmpeq(&self, other: Vec4) -> (bool, bool, bool, bool) {
+        (self.x == other.x, self.y == other.y, self.z == other.z, self.w == other.w)
+    }
+
+    /// Compare each component for less than `other`
+    #[must_use]
+    pub fn cmplt(&self, other: Vec4) -> (bool, bool, bool, bool) {
+        (self.x < other.x, self.y < other.y, self.z < other.z, self.w < other.w)
+    }
+
+    /// Compare each component for less than or equal to `other`
+    #[must_use]
+    pub fn cmple(&self, other: Vec4) -> (bool, bool, bool, bool) {
+        (self.x <= other.x, self.y <= other.y, self.z <= other.z, self.w <= other.w)
+    }
+
+    /// Compare each component for greater than `other`
+    #[must_use]
+    pub fn cmpgt(&self, other: Vec4) -> (bool, bool, bool, bool) {
+        (self.x > other.x, self.y > other.y, self.z > other.z, self.w > other.w)
+    }
+
+    /// Compare each component for greater than or equal to `other`
+    #[must_use]
+    pub fn cmpge(&self, other: Vec4) -> (bool, bool, bool, bool) {
+        (self.x >= other.x, self.y >= other.y, self.z >= other.z, self.w >= other.w)
+    }
+
+    /// Pick each component from `a` where `mask` is true, otherwise from `b`
+    #[must_use]
+    pub fn select(mask: (bool, bool, bool, bool), a: Vec4, b: Vec4) -> Vec4 {
+        let (x, y, z, w) = mask;
+        Vec4 {
+            x: if x { a.x } else { b.x },
+            y: if y { a.y } else { b.y },
+            z: if z { a.z } else { b.z },
+            w: if w { a.w } else { b.w },
+        }
+    }
+
     /// Return the component-wise absolute value
     #[must_use]
     pub fn abs(&self) -> Vec4 {
@@ -312,6 +524,28 @@ impl Vec4 {
         }
     }
 
+    /// Apply `f` to each component
+    #[must_use]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Vec4 {
+        Vec4 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+            w: f(self.w),
+        }
+    }
+
+    /// Combine this vector with another component-wise using `f`
+    #[must_use]
+    pub fn zip_map<F: Fn(f32, f32) -> f32>(&self, other: Vec4, f: F) -> Vec4 {
+        Vec4 {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+            w: f(self.w, other.w),
+        }
+    }
+
     /// Perform perspective division (divide xyz by w)
     ///
     /// This converts from homogeneous coordinates to 3D Cartesian coordinates.
@@ -331,6 +565,23 @@ impl Vec4 {
         (self.x, self.y, self.z)
     }
 
+    /// Interpret this as a homogeneous point and convert it to 3D
+    ///
+    /// Performs the perspective divide. Returns None if w is too close to zero,
+    /// i.e. the vector is a direction or a point at infinity.
+    #[must_use]
+    pub fn to_point(&self) -> Option<Vec3> {
+        self.perspective_divide().map(Vec3::from)
+    }
+
+    /// Interpret this as a homogeneous direction and convert it to 3D
+    ///
+    /// Drops w without dividing, so it is meaningful for w = 0 vectors.
+    #[must_use]
+    pub fn to_direction(&self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
     /// Check if this is a homogeneous position (w ≈ 1)
     #[must_use]
     pub fn is_position(&self) -> bool {
@@ -468,6 +719,32 @@ impl DivAssign<f32> for Vec4 {
     }
 }
 
+/// Component-wise sum, starting from zero
+impl std::iter::Sum for Vec4 {
+    fn sum<I: Iterator<Item = Vec4>>(iter: I) -> Vec4 {
+        iter.fold(Vec4::zero(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Vec4> for Vec4 {
+    fn sum<I: Iterator<Item = &'a Vec4>>(iter: I) -> Vec4 {
+        iter.copied().sum()
+    }
+}
+
+/// Component-wise product, starting from one
+impl std::iter::Product for Vec4 {
+    fn product<I: Iterator<Item = Vec4>>(iter: I) -> Vec4 {
+        iter.fold(Vec4::unit(), |acc, v| Vec4 { x: acc.x * v.x, y: acc.y * v.y, z: acc.z * v.z, w: acc.w * v.w })
+    }
+}
+
+impl<'a> std::iter::Product<&'a Vec4> for Vec4 {
+    fn product<I: Iterator<Item = &'a Vec4>>(iter: I) -> Vec4 {
+        iter.copied().product()
+    }
+}
+
 impl Index<usize> for Vec4 {
     type Output = f32;
 
@@ -524,6 +801,34 @@ impl From<Vec4> for [f32; 4] {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<glam::Vec4> for Vec4 {
+    fn from(v: glam::Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec4> for glam::Vec4 {
+    fn from(v: Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector4<f32>> for Vec4 {
+    fn from(v: nalgebra::Vector4<f32>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec4> for nalgebra::Vector4<f32> {
+    fn from(v: Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
 // ============================================
 // Tests
 // ============================================
@@ -597,4 +902,158 @@ mod tests {
         assert_eq!(color.z, 0.25);  // blue
         assert_eq!(color.w, 1.0);   // alpha
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_normalize_or() {
+        let zero = Vec4::zero();
+        assert_eq!(zero.normalize_or(Vec4::unit()), Vec4::unit());
+        assert_eq!(zero.normalize_or_zero(), Vec4::zero());
+
+        let v = Vec4::new(0.0, 0.0, 3.0, 4.0);
+        assert!(v.normalize_or(Vec4::unit()).approx_eq(Vec4::new(0.0, 0.0, 0.6, 0.8)));
+        assert!(v.normalize_or_zero().approx_eq(v.normalize()));
+    }
+
+    #[test]
+    fn test_finite_and_nan() {
+        let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(v.is_finite());
+        assert!(!v.has_nan());
+
+        let mut nan = v;
+        nan.y = f32::NAN;
+        assert!(!nan.is_finite());
+        assert!(nan.has_nan());
+
+        let mut inf = v;
+        inf.x = f32::INFINITY;
+        assert!(!inf.is_finite());
+        assert!(!inf.has_nan());
+    }
+
+    #[test]
+    fn test_comparison_masks() {
+        let a = Vec4::new(1.0, 5.0, -3.0, 2.0);
+        let b = Vec4::new(2.0, 4.0, 0.0, 2.0);
+        assert_eq!(a.cmplt(b), (true, false, true, false));
+        assert_eq!(a.cmpge(b), (false, true, false, true));
+        assert_eq!(a.cmpeq(b), (false, false, false, true));
+
+        // Branchless component-wise minimum
+        assert_eq!(Vec4::select(a.cmplt(b), a, b), a.min(b));
+        assert_eq!(Vec4::select(a.cmpgt(b), a, b), a.max(b));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let v = Vec4::new(0.0, 3.0, 0.0, 4.0);
+
+        let below = (v * 0.2).clamp_length(2.0, 10.0);
+        assert!((below.magnitude() - 2.0).abs() < EPSILON);
+        assert!(below.normalize().approx_eq(v.normalize()));
+
+        assert!(v.clamp_length(2.0, 10.0).approx_eq(v));
+
+        let above = (v * 4.0).clamp_length(2.0, 10.0);
+        assert!((above.magnitude() - 10.0).abs() < 1e-5);
+        assert!(above.normalize().approx_eq(v.normalize()));
+
+        assert!(Vec4::zero().clamp_length_min(1.0).is_zero());
+    }
+
+    #[test]
+    fn test_homogeneous_conversions() {
+        let p = Vec4::new(2.0, 4.0, 6.0, 2.0);
+        assert_eq!(p.to_point(), Some(Vec3::new(1.0, 2.0, 3.0)));
+
+        let d = Vec4::from_direction(Vec3::new(1.0, 0.0, -1.0));
+        assert_eq!(d.w, 0.0);
+        assert_eq!(d.to_point(), None);
+        assert_eq!(d.to_direction(), Vec3::new(1.0, 0.0, -1.0));
+
+        let q = Vec4::from_point(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(q, Vec4::position(1.0, 2.0, 3.0));
+        assert_eq!(q.to_point(), Some(Vec3::new(1.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let v = [Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::new(2.0, 2.0, 2.0, 2.0)];
+        assert_eq!(v.iter().sum::<Vec4>(), Vec4::new(3.0, 4.0, 5.0, 6.0));
+        assert_eq!(v.iter().copied().product::<Vec4>(), Vec4::new(2.0, 4.0, 6.0, 8.0));
+    }
+
+    #[test]
+    fn test_normalize_in_place() {
+        let mut v = Vec4::new(1.0, 2.0, 2.0, 4.0);
+        assert!(v.normalize_in_place());
+        assert_eq!(v, Vec4::new(1.0, 2.0, 2.0, 4.0).normalize());
+
+        let mut zero = Vec4::zero();
+        assert!(!zero.normalize_in_place());
+        assert_eq!(zero, Vec4::zero());
+    }
+
+    #[test]
+    fn test_map_and_zip_map() {
+        let v = Vec4::new(1.0, -2.0, 3.0, -4.0);
+        assert_eq!(v.map(|c| c * c), Vec4::new(1.0, 4.0, 9.0, 16.0));
+        assert_eq!(v.zip_map(Vec4::zero(), f32::max), v.max(Vec4::zero()));
+    }
+
+    #[test]
+    fn test_dot3_cross3() {
+        let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vec4::new(-2.0, 0.5, 1.0, 8.0);
+
+        assert_eq!(a.dot3(b), a.to_direction().dot(b.to_direction()));
+        assert_ne!(a.dot3(b), a.dot(b));
+
+        let cross = a.cross3(b);
+        assert_eq!(cross, Vec4::from_direction(a.to_direction().cross(b.to_direction())));
+        assert_eq!(cross.w, 0.0);
+        assert!(cross.dot3(a).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_lerp_clamped() {
+        let a = Vec4::new(0.0, 0.0, 0.0, 0.0);
+        let b = Vec4::new(2.0, 4.0, -2.0, 6.0);
+
+        assert_eq!(a.lerp(b, -0.5), Vec4::new(-1.0, -2.0, 1.0, -3.0));
+        assert_eq!(a.lerp_clamped(b, -0.5), a);
+        assert_eq!(a.lerp_clamped(b, 1.5), b);
+        assert_eq!(a.lerp_clamped(b, 0.25), a.lerp(b, 0.25));
+
+        assert!((Vec4::inverse_lerp(a, b, a.lerp(b, 0.75)) - 0.75).abs() < 1e-6);
+        assert!((Vec4::inverse_lerp(a, b, a.lerp(b, -0.5)) + 0.5).abs() < 1e-6);
+        assert_eq!(Vec4::inverse_lerp(a, a, b), 0.0);
+    }
+
+    #[test]
+    fn test_splines() {
+        let (p0, p1, p2, p3) = (Vec4::new(0.0, 0.0, 0.0, 1.0), Vec4::new(1.0, 2.0, 0.0, 1.0), Vec4::new(3.0, 2.0, 1.0, 1.0), Vec4::new(4.0, 0.0, 1.0, 1.0));
+
+        assert!(Vec4::catmull_rom(p0, p1, p2, p3, 0.0).approx_eq(p1));
+        assert!(Vec4::catmull_rom(p0, p1, p2, p3, 1.0).approx_eq(p2));
+
+        // Evenly spaced collinear points give plain linear motion
+        let step = p2 - p1;
+        let mid = Vec4::catmull_rom(p1 - step, p1, p2, p2 + step, 0.5);
+        assert!(mid.approx_eq(p1.lerp(p2, 0.5)));
+
+        let m = Vec4::zero();
+        assert!(Vec4::hermite(p1, m, p2, m, 0.0).approx_eq(p1));
+        assert!(Vec4::hermite(p1, m, p2, m, 1.0).approx_eq(p2));
+        assert!(Vec4::hermite(p1, m, p2, m, 0.5).approx_eq(p1.lerp(p2, 0.5)));
+    }
+
+    #[test]
+    fn test_approx_eq_eps() {
+        let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vec4::new(1.0, 2.0, 3.0001, 4.0);
+        assert!(!a.approx_eq(b));
+        assert!(a.approx_eq_eps(b, 1e-3));
+        assert!(!a.approx_eq_eps(b, 1e-5));
+    }
+}
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
-use crate::{import_symbol, Str, Var, Any, Vec2, Vec3, Vec4, Mat4x4};
+use std::mem::ManuallyDrop;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub};
+use crate::{import_symbol, Str, Var, Any, Type, Vec2, Vec3, Vec4, Mat4x4};
 
 // Vector constructors
 import_symbol!(construct_vector_bool, CONSTRUCT_VECTOR_BOOL, init_construct_vector_bool, (data: *const bool, size: usize) -> Arr<bool>);
@@ -112,6 +114,28 @@ import_symbol!(assign_vector_vector3, ASSIGN_VECTOR_VECTOR3, init_assign_vector_
 import_symbol!(assign_vector_vector4, ASSIGN_VECTOR_VECTOR4, init_assign_vector_vector4, (vec: *mut Arr<Vec4>, data: *const Vec4, size: usize) -> ());
 import_symbol!(assign_vector_matrix4x4, ASSIGN_VECTOR_MATRIX4X4, init_assign_vector_matrix4x4, (vec: *mut Arr<Mat4x4>, data: *const Mat4x4, size: usize) -> ());
 
+// Vector range appends (optional, see `Arr::extend_from_slice`)
+import_symbol!(unsafe append_vector_bool, APPEND_VECTOR_BOOL, init_append_vector_bool, has_append_vector_bool, (vec: *mut Arr<bool>, data: *const bool, size: usize) -> ());
+import_symbol!(unsafe append_vector_char8, APPEND_VECTOR_CHAR8, init_append_vector_char8, has_append_vector_char8, (vec: *mut Arr<i8>, data: *const i8, size: usize) -> ());
+import_symbol!(unsafe append_vector_char16, APPEND_VECTOR_CHAR16, init_append_vector_char16, has_append_vector_char16, (vec: *mut Arr<u16>, data: *const u16, size: usize) -> ());
+import_symbol!(unsafe append_vector_int8, APPEND_VECTOR_INT8, init_append_vector_int8, has_append_vector_int8, (vec: *mut Arr<i8>, data: *const i8, size: usize) -> ());
+import_symbol!(unsafe append_vector_int16, APPEND_VECTOR_INT16, init_append_vector_int16, has_append_vector_int16, (vec: *mut Arr<i16>, data: *const i16, size: usize) -> ());
+import_symbol!(unsafe append_vector_int32, APPEND_VECTOR_INT32, init_append_vector_int32, has_append_vector_int32, (vec: *mut Arr<i32>, data: *const i32, size: usize) -> ());
+import_symbol!(unsafe append_vector_int64, APPEND_VECTOR_INT64, init_append_vector_int64, has_append_vector_int64, (vec: *mut Arr<i64>, data: *const i64, size: usize) -> ());
+import_symbol!(unsafe append_vector_uint8, APPEND_VECTOR_UINT8, init_append_vector_uint8, has_append_vector_uint8, (vec: *mut Arr<u8>, data: *const u8, size: usize) -> ());
+import_symbol!(unsafe append_vector_uint16, APPEND_VECTOR_UINT16, init_append_vector_uint16, has_append_vector_uint16, (vec: *mut Arr<u16>, data: *const u16, size: usize) -> ());
+import_symbol!(unsafe append_vector_uint32, APPEND_VECTOR_UINT32, init_append_vector_uint32, has_append_vector_uint32, (vec: *mut Arr<u32>, data: *const u32, size: usize) -> ());
+import_symbol!(unsafe append_vector_uint64, APPEND_VECTOR_UINT64, init_append_vector_uint64, has_append_vector_uint64, (vec: *mut Arr<u64>, data: *const u64, size: usize) -> ());
+import_symbol!(unsafe append_vector_pointer, APPEND_VECTOR_POINTER, init_append_vector_pointer, has_append_vector_pointer, (vec: *mut Arr<usize>, data: *const usize, size: usize) -> ());
+import_symbol!(unsafe append_vector_float, APPEND_VECTOR_FLOAT, init_append_vector_float, has_append_vector_float, (vec: *mut Arr<f32>, data: *const f32, size: usize) -> ());
+import_symbol!(unsafe append_vector_double, APPEND_VECTOR_DOUBLE, init_append_vector_double, has_append_vector_double, (vec: *mut Arr<f64>, data: *const f64, size: usize) -> ());
+import_symbol!(unsafe append_vector_string, APPEND_VECTOR_STRING, init_append_vector_string, has_append_vector_string, (vec: *mut Arr<Str>, data: *const Str, size: usize) -> ());
+import_symbol!(unsafe append_vector_variant, APPEND_VECTOR_VARIANT, init_append_vector_variant, has_append_vector_variant, (vec: *mut Arr<Var>, data: *const Var, size: usize) -> ());
+import_symbol!(unsafe append_vector_vector2, APPEND_VECTOR_VECTOR2, init_append_vector_vector2, has_append_vector_vector2, (vec: *mut Arr<Vec2>, data: *const Vec2, size: usize) -> ());
+import_symbol!(unsafe append_vector_vector3, APPEND_VECTOR_VECTOR3, init_append_vector_vector3, has_append_vector_vector3, (vec: *mut Arr<Vec3>, data: *const Vec3, size: usize) -> ());
+import_symbol!(unsafe append_vector_vector4, APPEND_VECTOR_VECTOR4, init_append_vector_vector4, has_append_vector_vector4, (vec: *mut Arr<Vec4>, data: *const Vec4, size: usize) -> ());
+import_symbol!(unsafe append_vector_matrix4x4, APPEND_VECTOR_MATRIX4X4, init_append_vector_matrix4x4, has_append_vector_matrix4x4, (vec: *mut Arr<Mat4x4>, data: *const Mat4x4, size: usize) -> ());
+
 /// FFI-compatible vector type matching the memory layout of the C++ plg::vector<T>
 ///
 /// # Memory Layout
@@ -132,6 +156,9 @@ pub struct Arr<T: ArrOps> {
 }
 const _: () = assert!(size_of::<Arr<usize>>() == 3 * size_of::<*const ()>());
 
+/// Alias matching the C++ `plg::vector` name used in host headers
+pub type PlgVector<T> = Arr<T>;
+
 impl<T: ArrOps + std::fmt::Debug> std::fmt::Debug for Arr<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_list()
@@ -179,6 +206,13 @@ pub trait ArrOps: Sized {
     fn data_mut(vec: &mut Arr<Self>) -> *mut Self;
     fn set(vec: &mut Arr<Self>, data: &[Self]);
 
+    /// Append `data` through the host's `append_range`, if it provides one
+    ///
+    /// Returns false without touching `vec` when the host lacks the symbol.
+    fn append(_vec: &mut Arr<Self>, _data: &[Self]) -> bool {
+        false
+    }
+
     /// Get data as slice (zero-copy view)
     ///
     /// # Safety
@@ -346,6 +380,18 @@ impl<E: CEnumRepr> ArrOps for E {
             E::ReprInt::set(int_vec, int_data);
         }
     }
+
+    fn append(vec: &mut Arr<Self>, data: &[Self]) -> bool {
+        unsafe {
+            // SAFETY: Same casts as `set`
+            let int_vec: &mut Arr<E::ReprInt> = std::mem::transmute(vec);
+            let int_data = std::slice::from_raw_parts(
+                data.as_ptr() as *const E::ReprInt,
+                data.len()
+            );
+            E::ReprInt::append(int_vec, int_data)
+        }
+    }
 }
 
 #[macro_export]
@@ -357,10 +403,49 @@ macro_rules! vector_ops_traits {
         $len:path,
         $data:path,
         $assign:path
+    ) => {
+        $crate::vector_ops_traits!(@impl $t, $construct, $destroy, $len, $data, $assign, {});
+    };
+    (
+        $t:ty,
+        $construct:path,
+        $destroy:path,
+        $len:path,
+        $data:path,
+        $assign:path,
+        $append:path,
+        $has_append:path
+    ) => {
+        $crate::vector_ops_traits!(@impl $t, $construct, $destroy, $len, $data, $assign, {
+            fn append(vec: &mut Arr<$t>, data: &[Self]) -> bool {
+                if !$has_append() {
+                    return false;
+                }
+                // SAFETY: `vec` is a live vector and `data` is valid for `data.len()` reads
+                unsafe { $append(vec, data.as_ptr(), data.len()) };
+                if !data.is_empty() && vec.is_unallocated() {
+                    $crate::policy::alloc_failed("vector", data.len());
+                }
+                true
+            }
+        });
+    };
+    (
+        @impl $t:ty,
+        $construct:path,
+        $destroy:path,
+        $len:path,
+        $data:path,
+        $assign:path,
+        { $($extra:tt)* }
     ) => {
         impl ArrOps for $t {
             fn new(data: &[Self]) -> Arr<$t> {
-                $construct(data.as_ptr(), data.len())
+                let vec = $construct(data.as_ptr(), data.len());
+                if !data.is_empty() && vec.is_unallocated() {
+                    $crate::policy::alloc_failed("vector", data.len());
+                }
+                vec
             }
 
             fn destroy(vec: &mut Arr<$t>) {
@@ -380,8 +465,13 @@ macro_rules! vector_ops_traits {
             }
 
             fn set(vec: &mut Arr<$t>, data: &[Self]) {
-                $assign(vec, data.as_ptr(), data.len())
+                $assign(vec, data.as_ptr(), data.len());
+                if !data.is_empty() && vec.is_unallocated() {
+                    $crate::policy::alloc_failed("vector", data.len());
+                }
             }
+
+            $($extra)*
         }
     };
 }
@@ -392,7 +482,9 @@ vector_ops_traits!(
     destroy_vector_bool,
     get_vector_size_bool,
     get_vector_data_bool,
-    assign_vector_bool
+    assign_vector_bool,
+    append_vector_bool,
+    has_append_vector_bool
 );
 
 vector_ops_traits!(
@@ -401,7 +493,9 @@ vector_ops_traits!(
     destroy_vector_int8,
     get_vector_size_int8,
     get_vector_data_int8,
-    assign_vector_int8
+    assign_vector_int8,
+    append_vector_int8,
+    has_append_vector_int8
 );
 
 vector_ops_traits!(
@@ -410,7 +504,9 @@ vector_ops_traits!(
     destroy_vector_int16,
     get_vector_size_int16,
     get_vector_data_int16,
-    assign_vector_int16
+    assign_vector_int16,
+    append_vector_int16,
+    has_append_vector_int16
 );
 
 vector_ops_traits!(
@@ -419,7 +515,9 @@ vector_ops_traits!(
     destroy_vector_int32,
     get_vector_size_int32,
     get_vector_data_int32,
-    assign_vector_int32
+    assign_vector_int32,
+    append_vector_int32,
+    has_append_vector_int32
 );
 
 vector_ops_traits!(
@@ -428,7 +526,9 @@ vector_ops_traits!(
     destroy_vector_int64,
     get_vector_size_int64,
     get_vector_data_int64,
-    assign_vector_int64
+    assign_vector_int64,
+    append_vector_int64,
+    has_append_vector_int64
 );
 
 vector_ops_traits!(
@@ -437,7 +537,9 @@ vector_ops_traits!(
     destroy_vector_uint8,
     get_vector_size_uint8,
     get_vector_data_uint8,
-    assign_vector_uint8
+    assign_vector_uint8,
+    append_vector_uint8,
+    has_append_vector_uint8
 );
 
 vector_ops_traits!(
@@ -446,7 +548,9 @@ vector_ops_traits!(
     destroy_vector_uint16,
     get_vector_size_uint16,
     get_vector_data_uint16,
-    assign_vector_uint16
+    assign_vector_uint16,
+    append_vector_uint16,
+    has_append_vector_uint16
 );
 
 vector_ops_traits!(
@@ -455,7 +559,9 @@ vector_ops_traits!(
     destroy_vector_uint32,
     get_vector_size_uint32,
     get_vector_data_uint32,
-    assign_vector_uint32
+    assign_vector_uint32,
+    append_vector_uint32,
+    has_append_vector_uint32
 );
 
 vector_ops_traits!(
@@ -464,7 +570,9 @@ vector_ops_traits!(
     destroy_vector_uint64,
     get_vector_size_uint64,
     get_vector_data_uint64,
-    assign_vector_uint64
+    assign_vector_uint64,
+    append_vector_uint64,
+    has_append_vector_uint64
 );
 
 vector_ops_traits!(
@@ -473,7 +581,9 @@ vector_ops_traits!(
     destroy_vector_pointer,
     get_vector_size_pointer,
     get_vector_data_pointer,
-    assign_vector_pointer
+    assign_vector_pointer,
+    append_vector_pointer,
+    has_append_vector_pointer
 );
 
 vector_ops_traits!(
@@ -482,7 +592,9 @@ vector_ops_traits!(
     destroy_vector_float,
     get_vector_size_float,
     get_vector_data_float,
-    assign_vector_float
+    assign_vector_float,
+    append_vector_float,
+    has_append_vector_float
 );
 
 vector_ops_traits!(
@@ -491,7 +603,9 @@ vector_ops_traits!(
     destroy_vector_double,
     get_vector_size_double,
     get_vector_data_double,
-    assign_vector_double
+    assign_vector_double,
+    append_vector_double,
+    has_append_vector_double
 );
 
 vector_ops_traits!(
@@ -500,7 +614,9 @@ vector_ops_traits!(
     destroy_vector_vector2,
     get_vector_size_vector2,
     get_vector_data_vector2,
-    assign_vector_vector2
+    assign_vector_vector2,
+    append_vector_vector2,
+    has_append_vector_vector2
 );
 
 vector_ops_traits!(
@@ -509,7 +625,9 @@ vector_ops_traits!(
     destroy_vector_vector3,
     get_vector_size_vector3,
     get_vector_data_vector3,
-    assign_vector_vector3
+    assign_vector_vector3,
+    append_vector_vector3,
+    has_append_vector_vector3
 );
 
 vector_ops_traits!(
@@ -518,7 +636,9 @@ vector_ops_traits!(
     destroy_vector_vector4,
     get_vector_size_vector4,
     get_vector_data_vector4,
-    assign_vector_vector4
+    assign_vector_vector4,
+    append_vector_vector4,
+    has_append_vector_vector4
 );
 
 vector_ops_traits!(
@@ -527,7 +647,9 @@ vector_ops_traits!(
     destroy_vector_matrix4x4,
     get_vector_size_matrix4x4,
     get_vector_data_matrix4x4,
-    assign_vector_matrix4x4
+    assign_vector_matrix4x4,
+    append_vector_matrix4x4,
+    has_append_vector_matrix4x4
 );
 
 vector_ops_traits!(
@@ -536,7 +658,9 @@ vector_ops_traits!(
     destroy_vector_string,
     get_vector_size_string,
     get_vector_data_string,
-    assign_vector_string
+    assign_vector_string,
+    append_vector_string,
+    has_append_vector_string
 );
 
 vector_ops_traits!(
@@ -545,9 +669,28 @@ vector_ops_traits!(
     destroy_vector_variant,
     get_vector_size_variant,
     get_vector_data_variant,
-    assign_vector_variant
+    assign_vector_variant,
+    append_vector_variant,
+    has_append_vector_variant
 );
 
+/// Error returned by `Arr::try_get` and `Arr::try_get_mut` for an index past the end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    /// The index that was requested
+    pub index: usize,
+    /// The length of the container at the time
+    pub len: usize,
+}
+
+impl std::fmt::Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "index {} is out of bounds for length {}", self.index, self.len)
+    }
+}
+
+impl std::error::Error for IndexOutOfBounds {}
+
 // ============================================
 // Generic methods on Arr
 // ============================================
@@ -562,11 +705,21 @@ impl<T: ArrOps> Arr<T> {
     ///
     /// # Panics
     ///
-    /// May panic if the C++ allocation fails. The panic is safe - no resources will leak.
+    /// If the C++ allocation fails, panics or aborts according to the
+    /// [`AllocFailurePolicy`](crate::AllocFailurePolicy). The panic is safe - no resources will leak.
     pub fn from_slice(data: &[T]) -> Self {
         T::new(data)
     }
 
+    /// Construct a new Arr of `len` elements, each produced by calling `f` with its index
+    ///
+    /// Elements are collected into a temporary buffer and handed to the host in
+    /// one allocation.
+    pub fn from_fn<F: FnMut(usize) -> T>(len: usize, f: F) -> Self {
+        let data: Vec<T> = (0..len).map(f).collect();
+        T::new(&data)
+    }
+
     /// Get the length of the vector
     #[must_use]
     pub fn len(&self) -> usize {
@@ -598,21 +751,71 @@ impl<T: ArrOps> Arr<T> {
     }
 
     /// Get data by index
+    ///
+    /// Returns None if index is out of bounds
     #[must_use]
     pub fn get(&self, index: usize) -> Option<&T> {
         T::as_slice(self).get(index)
     }
 
+    /// Get a mutable reference to data by index
+    ///
+    /// Returns None if index is out of bounds
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        T::as_mut_slice(self).get_mut(index)
+    }
+
+    /// Get data by index, with the index and length in the error if it is out of bounds
+    pub fn try_get(&self, index: usize) -> Result<&T, IndexOutOfBounds> {
+        let len = T::len(self);
+        T::as_slice(self).get(index).ok_or(IndexOutOfBounds { index, len })
+    }
+
+    /// Get a mutable reference to data by index, with the index and length in the error if it is out of bounds
+    pub fn try_get_mut(&mut self, index: usize) -> Result<&mut T, IndexOutOfBounds> {
+        let len = T::len(self);
+        T::as_mut_slice(self).get_mut(index).ok_or(IndexOutOfBounds { index, len })
+    }
+
     /// Set new data to the vector, replacing previous contents
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// The C++ implementation must not throw exceptions. If allocation fails,
-    /// the process will abort.
+    /// If the host fails to allocate, panics or aborts according to the
+    /// [`AllocFailurePolicy`](crate::AllocFailurePolicy), leaving the vector empty.
     pub fn set(&mut self, data: &[T]) {
         T::set(self, data);
     }
 
+    /// Append the elements of a slice to the end of the vector
+    ///
+    /// Hosts that provide the optional `append_vector_<type>` symbols grow the
+    /// buffer in a single call. Otherwise the whole vector is rebuilt: the current
+    /// contents and `other` are cloned into a Rust buffer and assigned back, a
+    /// deep copy of every element on each call. Calling this in a loop on such a
+    /// host is O(n²); collect the elements first and `set` them once instead.
+    pub fn extend_from_slice(&mut self, other: &[T]) where T: Clone {
+        if other.is_empty() || T::append(self, other) {
+            return;
+        }
+        let mut buf = Vec::with_capacity(T::len(self) + other.len());
+        buf.extend_from_slice(T::as_slice(self));
+        buf.extend_from_slice(other);
+        T::set(self, &buf);
+    }
+
+    /// Concatenate two vectors, consuming both
+    ///
+    /// The elements of `other` are appended to this vector's buffer. For element
+    /// types without arithmetic, `a + b` does the same; on numeric vectors `+` is
+    /// element-wise addition instead, so use this there.
+    #[must_use]
+    pub fn concat(mut self, other: Arr<T>) -> Arr<T> where T: Clone {
+        self.extend_from_slice(other.as_slice());
+        self
+    }
+
     /// Destroy the vector (manual cleanup)
     ///
     /// This is typically not needed as Drop handles cleanup automatically.
@@ -621,6 +824,27 @@ impl<T: ArrOps> Arr<T> {
         T::destroy(self);
     }
 
+    /// Wrap the vector so its destructor never runs
+    ///
+    /// Escape hatch for handing the buffer over to the host: the returned value
+    /// can still be read and copied bitwise into host-owned storage. If nobody
+    /// takes ownership, the host allocation leaks.
+    #[must_use = "dropping the result leaks the host allocation"]
+    pub fn into_leaked(self) -> ManuallyDrop<Self> {
+        ManuallyDrop::new(self)
+    }
+
+    /// Detach this value from its host buffer so that dropping it does nothing
+    ///
+    /// Afterwards the value is an empty vector. The previous buffer is neither
+    /// destroyed nor reachable from here: copy the vector out first (e.g. with
+    /// `std::ptr::read`) if ownership is being transferred, otherwise it leaks.
+    pub fn forget_destroy(&mut self) {
+        self.begin = 0;
+        self.end = 0;
+        self.capacity = 0;
+    }
+
     /// Get an iterator over the vector elements
     pub fn iter(&self) -> std::slice::Iter<'_, T> {
         T::iter(self)
@@ -630,6 +854,201 @@ impl<T: ArrOps> Arr<T> {
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
         T::iter_mut(self)
     }
+
+    /// Get a mutable iterator over `(index, element)` pairs
+    pub fn enumerate_mut(&mut self) -> std::iter::Enumerate<std::slice::IterMut<'_, T>> {
+        T::iter_mut(self).enumerate()
+    }
+
+    /// Iterate over overlapping windows of `size` elements
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0
+    pub fn windows(&self, size: usize) -> std::slice::Windows<'_, T> {
+        T::as_slice(self).windows(size)
+    }
+
+    /// Iterate over non-overlapping chunks of exactly `size` elements
+    ///
+    /// Trailing elements that don't fill a chunk are available via `remainder()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0
+    pub fn chunks_exact(&self, size: usize) -> std::slice::ChunksExact<'_, T> {
+        T::as_slice(self).chunks_exact(size)
+    }
+
+    /// Split into fixed-size chunks of `N` elements and the leftover tail
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0
+    #[must_use]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let slice = T::as_slice(self);
+        let (head, tail) = slice.split_at(slice.len() / N * N);
+        // SAFETY: `head` is a whole number of runs of N elements, and `[T; N]`
+        // has the same layout as N consecutive `T`. This is `<[T]>::as_chunks`,
+        // which needs a newer toolchain than the crate otherwise does.
+        let chunks = unsafe { std::slice::from_raw_parts(head.as_ptr().cast::<[T; N]>(), head.len() / N) };
+        (chunks, tail)
+    }
+
+    /// Get the first element and the rest, or None if empty
+    #[must_use]
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        T::as_slice(self).split_first()
+    }
+
+    /// Get the last element and the rest, or None if empty
+    #[must_use]
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        T::as_slice(self).split_last()
+    }
+
+    /// Iterate over adjacent pairs `(a[0], a[1]), (a[1], a[2]), ...`
+    pub fn pairwise(&self) -> impl Iterator<Item = (&T, &T)> + '_ {
+        let slice = T::as_slice(self);
+        slice.iter().zip(slice.iter().skip(1))
+    }
+
+    /// Get the index of the first element matching `predicate`
+    #[must_use]
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        T::as_slice(self).iter().position(predicate)
+    }
+
+    /// Get the index of the last element matching `predicate`
+    #[must_use]
+    pub fn rposition<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        T::as_slice(self).iter().rposition(predicate)
+    }
+
+    /// Get the first element matching `predicate`
+    #[must_use]
+    pub fn find<P: FnMut(&&T) -> bool>(&self, predicate: P) -> Option<&T> {
+        T::as_slice(self).iter().find(predicate)
+    }
+
+    /// Check if the vector and `other` have the same length and `eq` holds for every pair
+    #[must_use]
+    pub fn eq_by<U, F: FnMut(&T, &U) -> bool>(&self, other: &[U], mut eq: F) -> bool {
+        let slice = T::as_slice(self);
+        slice.len() == other.len() && slice.iter().zip(other).all(|(a, b)| eq(a, b))
+    }
+
+    /// Overwrite every element with a clone of `value`
+    ///
+    /// Previous elements are dropped in place, so owned types such as
+    /// `Str` and `Var` release their allocations. The length is unchanged.
+    pub fn fill(&mut self, value: T) where T: Clone {
+        T::as_mut_slice(self).fill(value);
+    }
+
+    /// Overwrite every element with values returned by calling `f` repeatedly
+    ///
+    /// Previous elements are dropped in place. The length is unchanged.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        T::as_mut_slice(self).fill_with(f);
+    }
+
+    /// Keep only the elements for which `f` returns true
+    ///
+    /// See `retain_mut` for how the buffer is rebuilt.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) where T: Clone {
+        self.retain_mut(|value| f(value));
+    }
+
+    /// Keep only the elements for which `f` returns true, letting `f` mutate them
+    ///
+    /// Elements are visited once, in order. The host API has no in-place erase,
+    /// so kept elements are cloned into a new buffer that replaces the old one;
+    /// removed elements are dropped along with the old buffer.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) where T: Clone {
+        let mut kept = Vec::new();
+        let mut removed = false;
+        for value in T::as_mut_slice(self) {
+            if f(value) {
+                kept.push(value.clone());
+            } else {
+                removed = true;
+            }
+        }
+        if removed {
+            T::set(self, &kept);
+        }
+    }
+
+    /// Remove consecutive repeated elements, keeping the first of each run
+    ///
+    /// On sorted data this removes all duplicates. As with `retain`, the kept
+    /// elements are cloned into a new buffer, and only if something is removed.
+    pub fn dedup_consecutive(&mut self) where T: PartialEq + Clone {
+        let slice = T::as_slice(self);
+        if slice.windows(2).all(|w| w[0] != w[1]) {
+            return;
+        }
+        let mut kept: Vec<T> = Vec::with_capacity(slice.len());
+        for value in slice {
+            if kept.last() != Some(value) {
+                kept.push(value.clone());
+            }
+        }
+        T::set(self, &kept);
+    }
+
+    /// Sort the elements and remove duplicates
+    pub fn unique(&mut self) where T: Ord + Clone {
+        T::as_mut_slice(self).sort();
+        self.dedup_consecutive();
+    }
+
+    /// Check if the elements are sorted in ascending order
+    #[must_use]
+    pub fn is_sorted(&self) -> bool where T: PartialOrd {
+        T::as_slice(self).is_sorted()
+    }
+
+    /// Rotate the elements in place so that the element at `mid` becomes the first
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length.
+    pub fn rotate_left(&mut self, mid: usize) {
+        let len = self.len();
+        assert!(mid <= len, "rotate_left: mid {} out of bounds for length {}", mid, len);
+        T::as_mut_slice(self).rotate_left(mid);
+    }
+
+    /// Rotate the elements in place so that the last `k` elements come first
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length.
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len();
+        assert!(k <= len, "rotate_right: k {} out of bounds for length {}", k, len);
+        T::as_mut_slice(self).rotate_right(k);
+    }
+
+    /// Move the elements into a Rust-owned boxed slice and free the C++ vector
+    ///
+    /// The C++ buffer belongs to the host allocator, so one copy is unavoidable;
+    /// this copies directly into a single exact-size Rust allocation.
+    #[must_use = "this allocates and copies data into a new Box"]
+    pub fn into_boxed_slice(self) -> Box<[T]> where T: Copy {
+        Box::from(T::as_slice(&self))
+    }
+}
+
+/// Copy the elements into a Rust-owned Vec and free the C++ vector
+impl<T: ArrOps + Copy> From<Arr<T>> for Vec<T> {
+    fn from(arr: Arr<T>) -> Self {
+        T::to_vec(&arr)
+    }
 }
 
 impl<T: ArrOps> Index<usize> for Arr<T> {
@@ -648,10 +1067,483 @@ impl<T: ArrOps> IndexMut<usize> for Arr<T> {
 
 impl<T: ArrOps> Drop for Arr<T>  {
     fn drop(&mut self) {
+        // A detached vector (see `forget_destroy`) or a never-allocated empty
+        // one owns no buffer, and the host destroy would be a no-op
+        if self.is_unallocated() {
+            return;
+        }
         T::destroy(self);
     }
 }
 
+impl<T: ArrOps> Arr<T> {
+    /// Check for the all-zero value of a vector that owns no buffer
+    ///
+    /// Public only so the exported `vector_ops_traits!` macro can call it.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn is_unallocated(&self) -> bool {
+        self.begin == 0 && self.end == 0 && self.capacity == 0
+    }
+}
+
+// ============================================
+// Bool vector helpers
+// ============================================
+
+// plg::vector<bool> is not bit-packed like std::vector<bool>: the host stores
+// one byte per element, which is what makes the `&[bool]` view sound.
+const _: () = assert!(size_of::<bool>() == 1);
+
+impl Arr<bool> {
+    /// Get the elements as a slice, checking the host uses one byte per element
+    ///
+    /// # Panics
+    ///
+    /// Panics if the host buffer is not laid out as one byte per element
+    /// (e.g. a bit-packed vector), since the slice view would be unsound.
+    fn bits(&self) -> &[bool] {
+        let len = self.len();
+        assert!(
+            len == 0 || self.end - self.begin == len,
+            "host vector<bool> is not byte-per-element: {} bytes for {} elements",
+            self.end - self.begin,
+            len
+        );
+        self.as_slice()
+    }
+
+    /// Count the elements that are true
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.bits().iter().filter(|&&b| b).count()
+    }
+
+    /// Count the elements that are false
+    #[must_use]
+    pub fn count_zeros(&self) -> usize {
+        self.bits().iter().filter(|&&b| !b).count()
+    }
+
+    /// Check if every element is true (true for an empty vector)
+    #[must_use]
+    pub fn all(&self) -> bool {
+        self.bits().iter().all(|&b| b)
+    }
+
+    /// Check if any element is true (false for an empty vector)
+    #[must_use]
+    pub fn any(&self) -> bool {
+        self.bits().iter().any(|&b| b)
+    }
+
+    /// Set the element at `index` to `value`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        self.bits();
+        self.as_mut_slice()[index] = value;
+    }
+}
+
+// ============================================
+// Numeric reductions
+// ============================================
+
+macro_rules! vector_int_reductions {
+    ($t:ty, $wide:ty) => {
+        impl Arr<$t> {
+            /// Sum all elements, widened to avoid overflow
+            ///
+            /// Returns None if the sum overflows the wide type.
+            #[must_use]
+            pub fn checked_sum(&self) -> Option<$wide> {
+                self.as_slice().iter().try_fold(0 as $wide, |acc, &v| acc.checked_add(v as $wide))
+            }
+
+            /// Multiply all elements, widened to delay overflow
+            ///
+            /// Returns None if the product overflows the wide type.
+            /// The product of an empty vector is 1.
+            #[must_use]
+            pub fn checked_product(&self) -> Option<$wide> {
+                self.as_slice().iter().try_fold(1 as $wide, |acc, &v| acc.checked_mul(v as $wide))
+            }
+
+            /// Get the smallest element, or None if empty
+            #[must_use]
+            pub fn min(&self) -> Option<$t> {
+                self.as_slice().iter().copied().min()
+            }
+
+            /// Get the largest element, or None if empty
+            #[must_use]
+            pub fn max(&self) -> Option<$t> {
+                self.as_slice().iter().copied().max()
+            }
+
+            /// Get the arithmetic mean, or None if empty
+            #[must_use]
+            pub fn mean(&self) -> Option<f64> {
+                let len = self.len();
+                if len == 0 {
+                    return None;
+                }
+                let sum: f64 = self.as_slice().iter().map(|&v| v as f64).sum();
+                Some(sum / len as f64)
+            }
+
+            /// Clamp every element into `[min, max]` in place
+            ///
+            /// # Panics
+            ///
+            /// Panics if `min > max`.
+            pub fn clamp_each(&mut self, min: $t, max: $t) {
+                assert!(min <= max, "clamp_each: min > max");
+                for v in self.as_mut_slice() {
+                    *v = (*v).clamp(min, max);
+                }
+            }
+        }
+    };
+}
+
+vector_int_reductions!(i8, i64);
+vector_int_reductions!(i16, i64);
+vector_int_reductions!(i32, i64);
+vector_int_reductions!(i64, i128);
+vector_int_reductions!(u8, u64);
+vector_int_reductions!(u16, u64);
+vector_int_reductions!(u32, u64);
+vector_int_reductions!(u64, u128);
+
+macro_rules! vector_float_reductions {
+    ($t:ty) => {
+        impl Arr<$t> {
+            /// Get the smallest element, or None if empty
+            ///
+            /// NaN elements are ignored unless every element is NaN.
+            #[must_use]
+            pub fn min(&self) -> Option<$t> {
+                self.as_slice().iter().copied().reduce(<$t>::min)
+            }
+
+            /// Get the largest element, or None if empty
+            ///
+            /// NaN elements are ignored unless every element is NaN.
+            #[must_use]
+            pub fn max(&self) -> Option<$t> {
+                self.as_slice().iter().copied().reduce(<$t>::max)
+            }
+
+            /// Get the arithmetic mean in f64 precision, or None if empty
+            #[must_use]
+            pub fn mean(&self) -> Option<f64> {
+                let len = self.len();
+                if len == 0 {
+                    return None;
+                }
+                let sum: f64 = self.as_slice().iter().map(|&v| v as f64).sum();
+                Some(sum / len as f64)
+            }
+
+            /// Check if every element is within `epsilon` of the matching element of `other`
+            ///
+            /// Vectors of different lengths are never equal, and NaN never compares equal.
+            #[must_use]
+            pub fn approx_eq(&self, other: &[$t], epsilon: $t) -> bool {
+                self.eq_by(other, |a, b| (a - b).abs() <= epsilon)
+            }
+        }
+    };
+}
+
+vector_float_reductions!(f32);
+vector_float_reductions!(f64);
+
+// ============================================
+// Element-wise arithmetic
+// ============================================
+
+fn zip_with<T: ArrOps + Copy>(lhs: &Arr<T>, rhs: &Arr<T>, op: &str, f: impl Fn(T, T) -> T) -> Arr<T> {
+    let (a, b) = (lhs.as_slice(), rhs.as_slice());
+    assert_eq!(a.len(), b.len(), "length mismatch in Arr {}: {} vs {}", op, a.len(), b.len());
+    let data: Vec<T> = a.iter().zip(b).map(|(&x, &y)| f(x, y)).collect();
+    Arr::from_slice(&data)
+}
+
+// Binary operators panic if the lengths differ, like indexing out of bounds.
+// Integer overflow behaves as for the scalar operators: a panic in debug
+// builds, wrapping in release.
+macro_rules! vector_arith_ops {
+    ($t:ty) => {
+        impl Add for &Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn add(self, rhs: &Arr<$t>) -> Arr<$t> {
+                zip_with(self, rhs, "addition", |a, b| a + b)
+            }
+        }
+
+        impl Add for Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn add(self, rhs: Arr<$t>) -> Arr<$t> {
+                &self + &rhs
+            }
+        }
+
+        impl Sub for &Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn sub(self, rhs: &Arr<$t>) -> Arr<$t> {
+                zip_with(self, rhs, "subtraction", |a, b| a - b)
+            }
+        }
+
+        impl Sub for Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn sub(self, rhs: Arr<$t>) -> Arr<$t> {
+                &self - &rhs
+            }
+        }
+
+        impl Mul for &Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn mul(self, rhs: &Arr<$t>) -> Arr<$t> {
+                zip_with(self, rhs, "multiplication", |a, b| a * b)
+            }
+        }
+
+        impl Mul for Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn mul(self, rhs: Arr<$t>) -> Arr<$t> {
+                &self * &rhs
+            }
+        }
+
+        impl Mul<$t> for &Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn mul(self, scalar: $t) -> Arr<$t> {
+                let data: Vec<$t> = self.as_slice().iter().map(|&v| v * scalar).collect();
+                Arr::from_slice(&data)
+            }
+        }
+
+        impl Mul<$t> for Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn mul(self, scalar: $t) -> Arr<$t> {
+                &self * scalar
+            }
+        }
+    };
+}
+
+vector_arith_ops!(i8);
+vector_arith_ops!(i16);
+vector_arith_ops!(i32);
+vector_arith_ops!(i64);
+vector_arith_ops!(u8);
+vector_arith_ops!(u16);
+vector_arith_ops!(u32);
+vector_arith_ops!(u64);
+vector_arith_ops!(f32);
+vector_arith_ops!(f64);
+
+// Element types without arithmetic use `+` for concatenation
+macro_rules! vector_concat_ops {
+    ($($t:ty),+) => {
+        $(
+            impl Add for Arr<$t> {
+                type Output = Arr<$t>;
+
+                fn add(self, rhs: Arr<$t>) -> Arr<$t> {
+                    self.concat(rhs)
+                }
+            }
+        )+
+    };
+}
+
+vector_concat_ops!(bool, Str, Var, Vec2, Vec3, Vec4, Mat4x4);
+
+// Negation and in-place scalar ops mutate the host buffer directly, without reallocating.
+// Integer overflow, including negating `MIN`, behaves as for the scalar operators.
+macro_rules! vector_signed_ops {
+    ($t:ty) => {
+        impl Arr<$t> {
+            /// Negate every element in place
+            pub fn neg_assign(&mut self) {
+                for v in self.as_mut_slice() {
+                    *v = -*v;
+                }
+            }
+        }
+
+        impl Neg for &Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn neg(self) -> Arr<$t> {
+                let data: Vec<$t> = self.as_slice().iter().map(|&v| -v).collect();
+                Arr::from_slice(&data)
+            }
+        }
+
+        impl Neg for Arr<$t> {
+            type Output = Arr<$t>;
+
+            fn neg(mut self) -> Arr<$t> {
+                self.neg_assign();
+                self
+            }
+        }
+
+        impl AddAssign<$t> for Arr<$t> {
+            fn add_assign(&mut self, scalar: $t) {
+                for v in self.as_mut_slice() {
+                    *v += scalar;
+                }
+            }
+        }
+
+        impl MulAssign<$t> for Arr<$t> {
+            fn mul_assign(&mut self, scalar: $t) {
+                for v in self.as_mut_slice() {
+                    *v *= scalar;
+                }
+            }
+        }
+    };
+}
+
+vector_signed_ops!(i8);
+vector_signed_ops!(i16);
+vector_signed_ops!(i32);
+vector_signed_ops!(i64);
+vector_signed_ops!(f32);
+vector_signed_ops!(f64);
+
+// ============================================
+// Bit reinterpretation
+// ============================================
+
+mod plain {
+    /// Element types for which every bit pattern is a valid value
+    pub trait Plain: Copy {}
+}
+
+macro_rules! plain_element {
+    ($($t:ty),+) => {
+        $(impl plain::Plain for $t {})+
+    };
+}
+
+plain_element!(i8, i16, i32, i64, u8, u16, u32, u64, usize, f32, f64);
+
+impl<T: ArrOps + plain::Plain> Arr<T> {
+    /// View the elements as another type of the same size (zero-copy)
+    ///
+    /// This reinterprets the bits of each element; it is NOT a numeric conversion,
+    /// so `1.0f32` viewed as `u32` is `0x3f80_0000`, not `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` differs in size from `T` or needs a stricter alignment.
+    #[must_use]
+    pub fn reinterpret_cast<U: plain::Plain>(&self) -> &[U] {
+        let slice = self.as_slice();
+        assert_reinterpretable::<T, U>();
+        // SAFETY: Same size and compatible alignment were checked above, and every
+        // bit pattern of T is a valid U since both are plain numeric types.
+        unsafe { std::slice::from_raw_parts(slice.as_ptr().cast::<U>(), slice.len()) }
+    }
+
+    /// Mutable version of `reinterpret_cast`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` differs in size from `T` or needs a stricter alignment.
+    #[must_use]
+    pub fn reinterpret_cast_mut<U: plain::Plain>(&mut self) -> &mut [U] {
+        let slice = self.as_mut_slice();
+        assert_reinterpretable::<T, U>();
+        // SAFETY: As in `reinterpret_cast`; the exclusive borrow is carried over.
+        unsafe { std::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<U>(), slice.len()) }
+    }
+}
+
+fn assert_reinterpretable<T, U>() {
+    assert_eq!(size_of::<T>(), size_of::<U>(), "reinterpret_cast: element size mismatch");
+    assert!(align_of::<U>() <= align_of::<T>(), "reinterpret_cast: alignment mismatch");
+}
+
+impl Arr<u32> {
+    /// View the bits as `f32` values (zero-copy, not a numeric conversion)
+    #[must_use]
+    pub fn as_f32_slice(&self) -> &[f32] {
+        self.reinterpret_cast()
+    }
+}
+
+impl Arr<f32> {
+    /// View the bits as `u32` values (zero-copy, not a numeric conversion)
+    #[must_use]
+    pub fn as_u32_slice(&self) -> &[u32] {
+        self.reinterpret_cast()
+    }
+}
+
+impl Arr<u64> {
+    /// View the bits as `f64` values (zero-copy, not a numeric conversion)
+    #[must_use]
+    pub fn as_f64_slice(&self) -> &[f64] {
+        self.reinterpret_cast()
+    }
+}
+
+impl Arr<f64> {
+    /// View the bits as `u64` values (zero-copy, not a numeric conversion)
+    #[must_use]
+    pub fn as_u64_slice(&self) -> &[u64] {
+        self.reinterpret_cast()
+    }
+}
+
+// ============================================
+// UTF-16 string helpers
+// ============================================
+
+impl Arr<u16> {
+    /// Encode a string as a host UTF-16 (char16) vector
+    #[must_use]
+    pub fn from_utf16_str(s: &str) -> Self {
+        let units: Vec<u16> = s.encode_utf16().collect();
+        Self::from_slice(&units)
+    }
+
+    /// Decode the vector as a UTF-16 string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vector contains an unpaired surrogate.
+    pub fn to_string_utf16(&self) -> Result<String, std::string::FromUtf16Error> {
+        String::from_utf16(self.as_slice())
+    }
+
+    /// Decode the vector as a UTF-16 string, replacing unpaired surrogates with U+FFFD
+    #[must_use]
+    pub fn to_string_utf16_lossy(&self) -> String {
+        String::from_utf16_lossy(self.as_slice())
+    }
+}
+
 // ============================================
 // Convenient String/Any implementations
 // ============================================
@@ -688,6 +1580,17 @@ impl From<Vec<String>> for Arr<Str> {
 }
 
 impl Arr<Var> {
+    /// Create a vector of variants, checking that every element has the `expected` type
+    ///
+    /// On mismatch nothing is allocated and the index and actual type of the
+    /// first offending element are returned.
+    pub fn from_typed_slice(data: &[Any], expected: Type) -> Result<Self, (usize, Type)> {
+        if let Some((i, v)) = data.iter().enumerate().find(|(_, v)| v.current() != expected) {
+            return Err((i, v.current()));
+        }
+        Ok(Arr::from(data))
+    }
+
     #[must_use = "this allocates and converts to Vec<Any>"]
     pub fn to_any(&self) -> Vec<Any> {
         self.as_slice()
@@ -695,6 +1598,46 @@ impl Arr<Var> {
             .map(|s| s.get())
             .collect()
     }
+
+    /// Convert to a `Vec<Any>`, consuming the vector
+    ///
+    /// The payloads still have to be copied out of host memory once, but each
+    /// element's host buffer is released right after it is converted rather than
+    /// staying alive alongside the copies, and no `Arr<Var>` outlives the call.
+    #[must_use]
+    pub fn into_any_vec(mut self) -> Vec<Any> {
+        self.iter_mut().map(Var::take).collect()
+    }
+
+    /// Encode a string-keyed map as alternating key/value variants
+    ///
+    /// Even indices hold the keys as `String` variants and each following odd
+    /// index holds that key's value: `[k0, v0, k1, v1, ...]`. Entries are sorted
+    /// by key so the encoding is deterministic.
+    #[must_use]
+    pub fn from_str_map(map: &HashMap<String, Any>) -> Self {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        let pairs: Vec<Any> = entries.into_iter()
+            .flat_map(|(k, v)| [Any::String(k.clone()), v.clone()])
+            .collect();
+        Arr::from(pairs.as_slice())
+    }
+
+    /// Decode alternating key/value variants produced by `from_str_map`
+    ///
+    /// Pairs whose key is not a `String` variant are skipped, as is a trailing
+    /// key without a value. Later duplicates of a key win.
+    #[must_use = "this allocates and converts to HashMap<String, Any>"]
+    pub fn to_str_map(&self) -> HashMap<String, Any> {
+        self.as_slice()
+            .chunks_exact(2)
+            .filter_map(|pair| match pair[0].get() {
+                Any::String(key) => Some((key, pair[1].get())),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl From<&[Any]> for Arr<Var> {
@@ -828,4 +1771,607 @@ macro_rules! vector_enum_traits {
             "Enum alignment must match its repr type alignment"
         );
     };
-}
\ No newline at end of file
+}
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    #[test]
+    fn test_fill() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut floats = Arr::from_slice(&[1.0f32, 2.0, 3.0]);
+        floats.fill(0.5);
+        assert_eq!(floats.as_slice(), &[0.5, 0.5, 0.5]);
+
+        let mut counter = 0.0;
+        floats.fill_with(|| { counter += 1.0; counter });
+        assert_eq!(floats.as_slice(), &[1.0, 2.0, 3.0]);
+        assert_eq!(mock::live_vectors(), 1);
+
+        drop(floats);
+        assert_eq!(mock::live_vectors(), 0);
+    }
+
+    #[test]
+    fn test_fill_releases_strings() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut strings: Arr<Str> = Arr::from(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        assert_eq!(mock::live_strings(), 3);
+
+        strings.fill(Str::from("x"));
+        assert_eq!(strings.to_string(), vec!["x", "x", "x"]);
+        assert_eq!(mock::live_strings(), 3);
+
+        drop(strings);
+        assert_eq!(mock::live_strings(), 0);
+    }
+
+    #[test]
+    fn test_into_boxed_slice() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let ints = Arr::from_slice(&[1i32, 2, 3]);
+        assert_eq!(mock::live_vectors(), 1);
+
+        let boxed = ints.into_boxed_slice();
+        assert_eq!(&*boxed, &[1, 2, 3]);
+        assert_eq!(mock::live_vectors(), 0);
+    }
+
+    #[test]
+    fn test_bool_helpers() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut flags = Arr::from_slice(&[true, false, true, true, false]);
+        assert_eq!(flags.count_ones(), 3);
+        assert_eq!(flags.count_zeros(), 2);
+        assert!(flags.any());
+        assert!(!flags.all());
+
+        flags.set_bit(1, true);
+        flags.set_bit(4, true);
+        assert!(flags.all());
+
+        let empty: Arr<bool> = Arr::new();
+        assert!(empty.all());
+        assert!(!empty.any());
+    }
+
+    #[test]
+    fn test_reductions() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let big = Arr::from_slice(&[i32::MAX, i32::MAX, 2]);
+        assert_eq!(big.checked_sum(), Some(2 * i32::MAX as i64 + 2));
+        assert_eq!(big.checked_product(), Some(2 * (i32::MAX as i64) * (i32::MAX as i64)));
+        assert_eq!(big.min(), Some(2));
+        assert_eq!(big.max(), Some(i32::MAX));
+
+        let huge = Arr::from_slice(&[i64::MAX, i64::MAX, i64::MAX]);
+        assert_eq!(huge.checked_product(), None);
+
+        let floats = Arr::from_slice(&[1.5f32, -2.0, 3.5]);
+        assert_eq!(floats.min(), Some(-2.0));
+        assert_eq!(floats.max(), Some(3.5));
+        assert_eq!(floats.mean(), Some(1.0));
+
+        let empty: Arr<u8> = Arr::new();
+        assert_eq!(empty.checked_sum(), Some(0));
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.max(), None);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let evens = Arr::from_fn(5, |i| i as i32 * 2);
+        assert_eq!(evens.as_slice(), &[0, 2, 4, 6, 8]);
+
+        let names: Arr<Str> = Arr::from_fn(2, |i| Str::from(format!("item{i}")));
+        assert_eq!(names.to_string(), vec!["item0", "item1"]);
+        drop(names);
+        assert_eq!(mock::live_strings(), 0);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut ints = Arr::from_slice(&[1i32, 2, 3, 4, 5, 6]);
+        ints.retain_mut(|v| {
+            *v *= 10;
+            *v % 20 != 0
+        });
+        assert_eq!(ints.as_slice(), &[10, 30, 50]);
+
+        let mut names: Arr<Str> = Arr::from(vec!["keep".to_string(), "drop".to_string(), "keep".to_string()]);
+        names.retain_mut(|s| {
+            if s == "keep" {
+                s.set("kept");
+                true
+            } else {
+                false
+            }
+        });
+        assert_eq!(names.to_string(), vec!["kept", "kept"]);
+        assert_eq!(mock::live_strings(), 2);
+
+        names.retain(|s| s.is_empty());
+        assert!(names.is_empty());
+        assert_eq!(mock::live_strings(), 0);
+    }
+
+    #[test]
+    fn test_utf16_arr() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let wide = Arr::from_utf16_str("a😀");
+        assert_eq!(wide.as_slice(), &[0x0061, 0xD83D, 0xDE00]);
+        assert_eq!(wide.to_string_utf16().unwrap(), "a😀");
+
+        let broken = Arr::from_slice(&[0x0061u16, 0xDE00]);
+        assert!(broken.to_string_utf16().is_err());
+        assert_eq!(broken.to_string_utf16_lossy(), "a\u{FFFD}");
+    }
+
+    #[test]
+    fn test_elementwise_ops() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let a = Arr::from_slice(&[1.0f32, 2.0, 3.0]);
+        let b = Arr::from_slice(&[0.5f32, 0.5, 1.0]);
+        assert_eq!((&a + &b).as_slice(), &[1.5, 2.5, 4.0]);
+        assert_eq!((&a - &b).as_slice(), &[0.5, 1.5, 2.0]);
+        assert_eq!((&a * &b).as_slice(), &[0.5, 1.0, 3.0]);
+        assert_eq!((a * 2.0).as_slice(), &[2.0, 4.0, 6.0]);
+
+        let sum = Arr::from_slice(&[1i32, 2]) + Arr::from_slice(&[10i32, 20]);
+        assert_eq!(sum.as_slice(), &[11, 22]);
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch")]
+    fn test_elementwise_length_mismatch() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let _ = Arr::from_slice(&[1.0f64, 2.0]) + Arr::from_slice(&[1.0f64]);
+    }
+
+    #[test]
+    fn test_windows_and_pairs() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let samples = Arr::from_slice(&[1.0f32, 4.0, 9.0, 16.0, 25.0]);
+        let deltas: Vec<f32> = samples.pairwise().map(|(a, b)| b - a).collect();
+        assert_eq!(deltas, vec![3.0, 5.0, 7.0, 9.0]);
+
+        let window_deltas: Vec<f32> = samples.windows(2).map(|w| w[1] - w[0]).collect();
+        assert_eq!(window_deltas, deltas);
+
+        let chunks = samples.chunks_exact(2);
+        assert_eq!(chunks.remainder(), &[25.0]);
+        assert_eq!(chunks.map(|c| c[0] + c[1]).collect::<Vec<_>>(), vec![5.0, 25.0]);
+
+        let single = Arr::from_slice(&[1i32]);
+        assert_eq!(single.pairwise().count(), 0);
+    }
+
+    #[test]
+    fn test_forget_destroy() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let leaked = Arr::from_slice(&[1i32, 2, 3]).into_leaked();
+        assert_eq!(leaked.as_slice(), &[1, 2, 3]);
+        assert_eq!(mock::live_vectors(), 1);
+        drop(ManuallyDrop::into_inner(leaked));
+        assert_eq!(mock::live_vectors(), 0);
+
+        let mut arr = Arr::from_slice(&[4i32, 5]);
+        // Hand the buffer to the "host" before detaching
+        let host_owned = ManuallyDrop::new(unsafe { std::ptr::read(&arr) });
+        arr.forget_destroy();
+        assert!(arr.is_empty());
+        drop(arr);
+        assert_eq!(mock::live_vectors(), 1);
+
+        drop(ManuallyDrop::into_inner(host_owned));
+        assert_eq!(mock::live_vectors(), 0);
+    }
+
+    #[test]
+    fn test_from_typed_slice() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let args = [Any::Int32(1), Any::Int32(2), Any::Int32(3)];
+        let arr = Arr::<Var>::from_typed_slice(&args, Type::Int32).unwrap();
+        assert_eq!(arr.len(), 3);
+        assert!(arr.as_slice().iter().all(|v| v.current() == Type::Int32));
+
+        let mixed = [Any::Int32(1), Any::Float(2.0), Any::String("x".into())];
+        assert_eq!(
+            Arr::<Var>::from_typed_slice(&mixed, Type::Int32).err(),
+            Some((1, Type::Float))
+        );
+
+        let empty = Arr::<Var>::from_typed_slice(&[], Type::String).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_clamp_each() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut arr = Arr::from_slice(&[-50i32, 0, 7, 120]);
+        arr.clamp_each(-10, 100);
+        assert_eq!(arr.as_slice(), &[-10, 0, 7, 100]);
+    }
+
+    #[test]
+    fn test_reinterpret_cast() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let bits = Arr::from_slice(&[1.0f32.to_bits(), (-2.5f32).to_bits()]);
+        assert_eq!(bits.as_f32_slice(), &[1.0, -2.5]);
+
+        let floats = Arr::from_slice(&[1.0f32]);
+        assert_eq!(floats.as_u32_slice(), &[0x3f80_0000]);
+
+        let mut signed = Arr::from_slice(&[-1i64, 2]);
+        signed.reinterpret_cast_mut::<u64>()[1] = u64::MAX;
+        assert_eq!(signed.as_slice(), &[-1, -1]);
+        assert_eq!(signed.reinterpret_cast::<u64>(), &[u64::MAX, u64::MAX]);
+
+        let empty: Arr<u32> = Arr::new();
+        assert!(empty.as_f32_slice().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "element size mismatch")]
+    fn test_reinterpret_cast_size_mismatch() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let arr = Arr::from_slice(&[1u32]);
+        let _ = arr.reinterpret_cast::<u64>();
+    }
+
+    #[test]
+    fn test_position_and_find() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut arr = Arr::from_slice(&[4i32, 8, 15, 16, 8, 42]);
+        assert_eq!(arr.position(|&v| v == 8), Some(1));
+        assert_eq!(arr.rposition(|&v| v == 8), Some(4));
+        assert_eq!(arr.position(|&v| v == 7), None);
+        assert_eq!(arr.find(|&&v| v > 10), Some(&15));
+
+        for (i, v) in arr.enumerate_mut() {
+            *v += i as i32;
+        }
+        assert_eq!(arr.as_slice(), &[4, 9, 17, 19, 12, 47]);
+    }
+
+    #[test]
+    fn test_eq_by_and_approx_eq() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let a = Arr::from_slice(&[0.1f32 + 0.2, 1.0, -3.5]);
+        let b = Arr::from_slice(&[0.3f32, 1.0000001, -3.5]);
+        assert!(a.approx_eq(b.as_slice(), 1e-6));
+        assert!(!a.approx_eq(&[0.3, 1.0, -3.4], 1e-6));
+        assert!(!a.approx_eq(&[0.3, 1.0], 1e-6));
+
+        let ints = Arr::from_slice(&[1i32, 2, 3]);
+        assert!(ints.eq_by(&["1", "2", "3"], |v, s| v.to_string() == *s));
+        assert!(ints.as_slice().iter().eq([1, 2, 3].iter()));
+    }
+
+    #[test]
+    fn test_arr_var_macro() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let args = crate::arr_var![1i32, "hello", 3.5f64, Vec2::new(1.0, 2.0)];
+        let values = args.to_any();
+        assert_eq!(values.len(), 4);
+        assert!(matches!(values[0], Any::Int32(1)));
+        assert!(matches!(values[1], Any::String(ref s) if s == "hello"));
+        assert!(matches!(values[2], Any::Double(v) if v == 3.5));
+        assert!(matches!(values[3], Any::Vector2(v) if v == Vec2::new(1.0, 2.0)));
+
+        assert!(crate::arr_var![].is_empty());
+    }
+
+    #[test]
+    fn test_unique() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut arr = Arr::from_slice(&[5i32, 1, 3, 5, 1, 1, 9, 3]);
+        assert!(!arr.is_sorted());
+        arr.unique();
+        assert_eq!(arr.as_slice(), &[1, 3, 5, 9]);
+        assert!(arr.is_sorted());
+
+        let mut runs = Arr::from_slice(&[2i32, 2, 1, 1, 2]);
+        runs.dedup_consecutive();
+        assert_eq!(runs.as_slice(), &[2, 1, 2]);
+
+        let mut names = Arr::from(vec!["b".to_string(), "a".to_string(), "b".to_string()]);
+        names.unique();
+        assert_eq!(names.to_vec(), vec![Str::from("a"), Str::from("b")]);
+    }
+
+    #[test]
+    fn test_str_map_round_trip() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut map = HashMap::new();
+        map.insert("name".to_string(), Any::String("plugin".into()));
+        map.insert("threads".to_string(), Any::Int32(4));
+        map.insert("scale".to_string(), Any::Float(0.5));
+
+        let encoded = Arr::<Var>::from_str_map(&map);
+        assert_eq!(encoded.len(), 6);
+        assert!(matches!(encoded.as_slice()[0].get(), Any::String(ref k) if k == "name"));
+        assert!(matches!(encoded.as_slice()[1].get(), Any::String(ref v) if v == "plugin"));
+
+        let decoded = encoded.to_str_map();
+        assert_eq!(decoded.len(), 3);
+        assert!(matches!(decoded["threads"], Any::Int32(4)));
+        assert!(matches!(decoded["scale"], Any::Float(v) if v == 0.5));
+
+        let malformed = Arr::<Var>::from(vec![Any::Int32(1), Any::Bool(true), Any::String("x".into())]);
+        assert!(malformed.to_str_map().is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut arr = Arr::from_slice(&[1i32, 2, 3, 4, 5]);
+        arr.rotate_left(2);
+        assert_eq!(arr.as_slice(), &[3, 4, 5, 1, 2]);
+        arr.rotate_right(2);
+        assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+        arr.rotate_left(5);
+        assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_rotate_out_of_bounds() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut arr = Arr::from_slice(&[1i32, 2]);
+        arr.rotate_left(3);
+    }
+
+    #[test]
+    fn test_in_place_scalar_ops() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut samples = Arr::from_slice(&[0.5f32, -1.0, 2.0]);
+        let data = samples.as_slice().as_ptr();
+        samples *= 2.0;
+        samples += 1.0;
+        assert_eq!(samples.as_slice(), &[2.0, -1.0, 5.0]);
+        // Still the same host buffer
+        assert_eq!(samples.as_slice().as_ptr(), data);
+
+        samples.neg_assign();
+        assert_eq!(samples.as_slice(), &[-2.0, 1.0, -5.0]);
+        assert_eq!((-&samples).as_slice(), &[2.0, -1.0, 5.0]);
+
+        let ints = -Arr::from_slice(&[3i32, -4]);
+        assert_eq!(ints.as_slice(), &[-3, 4]);
+    }
+
+    #[test]
+    fn test_split_and_chunks() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let arr = Arr::from(vec![1i32, 2, 3]);
+        assert_eq!(arr.split_first(), Some((&1, &[2, 3][..])));
+        assert_eq!(arr.split_last(), Some((&3, &[1, 2][..])));
+        assert_eq!(Arr::<i32>::new().split_first(), None);
+
+        let vertices = Arr::from(vec![
+            Vec3::zero(), Vec3::right(), Vec3::up(),
+            Vec3::zero(), Vec3::up(), Vec3::forward(),
+            Vec3::unit(),
+        ]);
+        let (triangles, rest) = vertices.as_chunks::<3>();
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(triangles[1], [Vec3::zero(), Vec3::up(), Vec3::forward()]);
+        assert_eq!(rest, &[Vec3::unit()]);
+
+        let empty = Arr::<i32>::new();
+        let (pairs, rest) = empty.as_chunks::<2>();
+        assert!(pairs.is_empty() && rest.is_empty());
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut arr = Arr::from(vec![-1.0f32, -2.0]);
+        let batch: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+        arr.extend_from_slice(&batch);
+        assert_eq!(arr.len(), 1002);
+        assert_eq!(&arr.as_slice()[..2], &[-1.0, -2.0]);
+        assert_eq!(&arr.as_slice()[2..], &batch[..]);
+
+        arr.extend_from_slice(&[]);
+        assert_eq!(arr.len(), 1002);
+
+        let mut empty = Arr::<i32>::new();
+        empty.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(empty.as_slice(), &[1, 2, 3]);
+
+        let mut names = Arr::from(vec![Str::from("a")]);
+        names.extend_from_slice(&[Str::from("b"), Str::from("c")]);
+        assert_eq!(names.to_string(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_extend_from_slice_without_append() {
+        let _guard = mock::lock_globals();
+        mock::install();
+        init_append_vector_int32(0);
+        init_append_vector_string(0);
+        assert!(!has_append_vector_int32());
+
+        let mut arr = Arr::from(vec![1, 2]);
+        arr.extend_from_slice(&[3, 4, 5]);
+        assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(arr.concat(Arr::from(vec![6])).as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+        let mut names = Arr::from(vec![Str::from("a")]);
+        names.extend_from_slice(&[Str::from("b"), Str::from("c")]);
+        assert_eq!(names.to_string(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_into_any_vec() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let source = [
+            Any::String("hello".to_string()),
+            Any::ArrayInt32(vec![1, 2, 3]),
+            Any::Float(1.5),
+        ];
+        let arr = Arr::<Var>::from(&source[..]);
+        let expected = arr.to_any();
+        assert_eq!(mock::live_strings(), 1);
+
+        let values = arr.into_any_vec();
+        assert_eq!(mock::live_strings(), 0);
+        assert!(values.iter().map(Any::encode).eq(expected.iter().map(Any::encode)));
+        assert!(matches!(&values[0], Any::String(s) if s == "hello"));
+        assert!(matches!(&values[1], Any::ArrayInt32(v) if v == &[1, 2, 3]));
+        assert!(matches!(values[2], Any::Float(f) if f == 1.5));
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let before = mock::live_vectors();
+        let arr = Arr::from(vec![3i32, 1, 4, 1, 5]);
+        assert_eq!(mock::live_vectors(), before + 1);
+
+        let vec: Vec<i32> = arr.into();
+        assert_eq!(vec, [3, 1, 4, 1, 5]);
+        assert_eq!(mock::live_vectors(), before);
+
+        assert!(Vec::from(Arr::<f32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_drop_empty_skips_destroy() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let before = mock::destroy_calls();
+        drop(Arr::<i32>::new());
+        drop(Arr::<Str>::from_slice(&[]));
+        assert_eq!(mock::destroy_calls(), before);
+
+        drop(Arr::from(vec![1i32]));
+        assert_eq!(mock::destroy_calls(), before + 1);
+    }
+
+    #[test]
+    fn test_try_get() {
+        let _guard = mock::lock_globals();
+        mock::install();
+        let mut arr = Arr::from(vec![10i32, 20, 30]);
+        assert_eq!(arr.try_get(1), Ok(&20));
+        assert_eq!(arr.try_get(3), Err(IndexOutOfBounds { index: 3, len: 3 }));
+        assert_eq!(arr.try_get(3).unwrap_err().to_string(), "index 3 is out of bounds for length 3");
+
+        *arr.try_get_mut(0).unwrap() = 11;
+        *arr.get_mut(2).unwrap() = 33;
+        assert!(arr.get_mut(3).is_none());
+        assert_eq!(arr.try_get_mut(usize::MAX), Err(IndexOutOfBounds { index: usize::MAX, len: 3 }));
+        assert_eq!(arr.as_slice(), &[11, 20, 33]);
+
+        assert_eq!(Arr::<i32>::new().try_get(0), Err(IndexOutOfBounds { index: 0, len: 0 }));
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let _guard = mock::lock_globals();
+        mock::install();
+
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.get(2), Some(3.0));
+        assert_eq!(v.get(3), None);
+        *v.get_mut(0).unwrap() = 5.0;
+        assert_eq!(v[0], 5.0);
+        assert_eq!(Vec2::new(1.0, 2.0).get(2), None);
+        assert!(Vec4::zero().get_mut(4).is_none());
+
+        let mut m = Mat4x4::identity();
+        assert_eq!(m.get(3, 3), Some(1.0));
+        assert_eq!(m.get(0, 4), None);
+        assert!(m.get_mut(usize::MAX, 0).is_none());
+
+        let s = Str::from("h\u{e9}llo");
+        assert_eq!(s.get(0..1), Some("h"));
+        assert_eq!(s.get(0..2), None);
+        assert_eq!(s.get(0..10), None);
+    }
+
+    #[test]
+    fn test_concat() {
+        let _guard = mock::lock_globals();
+        mock::install();
+        let joined = Arr::from(vec![1i32, 2, 3]).concat(Arr::from(vec![4, 5]));
+        assert_eq!(joined.len(), 5);
+        assert_eq!(joined.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(Arr::<i32>::new().concat(joined).as_slice(), &[1, 2, 3, 4, 5]);
+
+        let names = Arr::from(vec![Str::from("a")]) + Arr::from(vec![Str::from("b"), Str::from("c")]);
+        assert_eq!(names.to_string(), ["a", "b", "c"]);
+        let flags = Arr::from(vec![true]) + Arr::from(vec![false]);
+        assert_eq!(flags.as_slice(), &[true, false]);
+
+        // `+` on numeric vectors still adds element-wise
+        assert_eq!((Arr::from(vec![1i32, 2]) + Arr::from(vec![3, 4])).as_slice(), &[4, 6]);
+    }
+}
@@ -0,0 +1,66 @@
+//! Build with `--no-default-features` to check that the deprecated named
+//! math methods are compiled out.
+//!
+//! Each probe trait provides a same-named method returning a marker type.
+//! Inherent methods win path resolution, so these calls only type-check once
+//! the inherent deprecated methods no longer exist.
+
+#![cfg(not(feature = "deprecated-methods"))]
+
+use plugify::{Mat4x4, Vec2, Vec3, Vec4};
+
+struct Gone;
+
+trait Probe<Rhs> {
+    fn add(&self, _rhs: Rhs) -> Gone {
+        Gone
+    }
+    fn subtract(&self, _rhs: Rhs) -> Gone {
+        Gone
+    }
+}
+
+trait ScaleProbe {
+    fn scale(&self, _scalar: f32) -> Gone {
+        Gone
+    }
+}
+
+trait MultiplyProbe {
+    fn multiply(&self, _rhs: Mat4x4) -> Gone {
+        Gone
+    }
+    fn multiply_vector(&self, _rhs: Vec4) -> Gone {
+        Gone
+    }
+}
+
+impl Probe<Vec2> for Vec2 {}
+impl Probe<Vec3> for Vec3 {}
+impl Probe<Vec4> for Vec4 {}
+impl Probe<Mat4x4> for Mat4x4 {}
+impl ScaleProbe for Vec2 {}
+impl ScaleProbe for Vec3 {}
+impl ScaleProbe for Vec4 {}
+impl MultiplyProbe for Mat4x4 {}
+
+#[test]
+fn deprecated_methods_are_gone() {
+    let _: Gone = Vec2::add(&Vec2::zero(), Vec2::zero());
+    let _: Gone = Vec2::subtract(&Vec2::zero(), Vec2::zero());
+    let _: Gone = Vec2::scale(&Vec2::zero(), 2.0);
+
+    let _: Gone = Vec3::add(&Vec3::zero(), Vec3::zero());
+    let _: Gone = Vec3::subtract(&Vec3::zero(), Vec3::zero());
+    let _: Gone = Vec3::scale(&Vec3::zero(), 2.0);
+
+    let _: Gone = Vec4::add(&Vec4::zero(), Vec4::zero());
+    let _: Gone = Vec4::subtract(&Vec4::zero(), Vec4::zero());
+    let _: Gone = Vec4::scale(&Vec4::zero(), 2.0);
+
+    let m = Mat4x4::identity();
+    let _: Gone = Mat4x4::add(&m, m);
+    let _: Gone = Mat4x4::subtract(&m, m);
+    let _: Gone = Mat4x4::multiply(&m, m);
+    let _: Gone = Mat4x4::multiply_vector(&m, Vec4::zero());
+}